@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use crate::config::GameConfig;
+use crate::game::GuessError;
+use crate::multi::MultiGame;
+use crate::scoring::{score_guess, LetterScore};
+use crate::words::WordList;
+
+/// Absurdle: a game with no fixed answer.
+///
+/// Every guess is scored against whichever pattern leaves the most words still
+/// possible, so the answer is only pinned down once there's no way around it.
+/// Any of the remaining candidates scores every earlier guess the same way,
+/// which is what lets a one board MultiGame play along with a stand-in answer:
+/// start it with new_game and send every guess through submit.
+#[derive(Debug, Clone)]
+pub struct Absurdle {
+    // answers that fit every pattern reported so far, in word list order.
+    candidates: Vec<String>,
+}
+
+impl Absurdle {
+    /// every answer in the list is still possible.
+    pub fn new(words: &WordList) -> Self {
+        Absurdle {
+            candidates: words.answers().to_vec(),
+        }
+    }
+
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    /// a word that scores every guess so far the way it was reported.
+    pub fn answer(&self) -> &str {
+        &self.candidates[0]
+    }
+
+    /// a game on the current stand-in answer. config should be for one board.
+    pub fn new_game(&self, config: GameConfig, words: WordList) -> MultiGame {
+        MultiGame::new(config, words, &[self.answer().to_string()])
+    }
+
+    /// submits the game's current guess after picking the answer again,
+    /// from the biggest group of candidates left by this guess.
+    /// the game should come from new_game.
+    pub fn submit(&mut self, game: &mut MultiGame) -> Result<(), GuessError> {
+        game.check_guess()?;
+        self.narrow(game.current_guess());
+        game.replace_answer(0, self.answer());
+        game.submit()
+    }
+
+    /// keeps the largest group of candidates that share a pattern for this guess
+    /// and returns that pattern.
+    /// ties go to the pattern that gives away the least, then to the group whose
+    /// first word comes first, so the same guesses always play out the same way.
+    pub fn narrow(&mut self, guess: &str) -> Vec<LetterScore> {
+        let mut groups: HashMap<Vec<LetterScore>, Vec<String>> = HashMap::new();
+        for candidate in self.candidates.drain(..) {
+            groups
+                .entry(score_guess(guess, &candidate))
+                .or_default()
+                .push(candidate);
+        }
+        let (pattern, group) = groups
+            .into_iter()
+            .max_by(|(a_pattern, a), (b_pattern, b)| {
+                a.len()
+                    .cmp(&b.len())
+                    .then_with(|| revealed(b_pattern).cmp(&revealed(a_pattern)))
+                    .then_with(|| b[0].cmp(&a[0]))
+            })
+            .expect("expect at least one candidate left");
+        self.candidates = group;
+        pattern
+    }
+}
+
+/// how much a pattern gives away, greens count double.
+fn revealed(pattern: &[LetterScore]) -> usize {
+    pattern
+        .iter()
+        .map(|score| match score {
+            LetterScore::Correct => 2,
+            LetterScore::Present => 1,
+            LetterScore::Absent => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring::LetterScore::*;
+
+    fn with_candidates(candidates: &[&str]) -> Absurdle {
+        Absurdle {
+            candidates: candidates.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn narrowing_keeps_the_largest_group() {
+        let mut absurdle = with_candidates(&["CRATE", "BUMPY", "CRAVE", "DUMPY", "JUMPY"]);
+        assert_eq!(absurdle.narrow("CRANE"), vec![Absent; 5]);
+        assert_eq!(absurdle.candidates(), ["BUMPY", "DUMPY", "JUMPY"]);
+        assert_eq!(absurdle.answer(), "BUMPY");
+    }
+
+    #[test]
+    fn ties_go_to_the_pattern_that_gives_away_the_least() {
+        // one word each, CRATE would show four greens.
+        let mut absurdle = with_candidates(&["CRATE", "BUMPY"]);
+        absurdle.narrow("CRANE");
+        assert_eq!(absurdle.candidates(), ["BUMPY"]);
+        // one green each, so the word that sorts first is kept.
+        let mut absurdle = with_candidates(&["DRILL", "CHILD"]);
+        assert_eq!(absurdle.narrow("CRANE"), vec![Correct, Absent, Absent, Absent, Absent]);
+        assert_eq!(absurdle.candidates(), ["CHILD"]);
+    }
+
+    #[test]
+    fn submitted_guesses_score_against_a_word_still_possible() {
+        let words = WordList::default();
+        let mut absurdle = Absurdle::new(&words);
+        let mut game = absurdle.new_game(GameConfig::default(), words.clone());
+        for guess in ["CRANE", "SLOTH"] {
+            guess.chars().try_for_each(|letter| game.append(letter)).unwrap();
+            absurdle.submit(&mut game).unwrap();
+            assert!(absurdle.candidates().len() < words.answers().len());
+            assert_eq!(game.answers(), vec![absurdle.answer()]);
+        }
+        let answer = absurdle.answer();
+        for row in game.boards()[0].rows() {
+            assert_eq!(row.scores, score_guess(&row.word, answer));
+        }
+        // a word that isn't in the list leaves the candidates alone.
+        let left = absurdle.candidates().len();
+        "QZXJK".chars().try_for_each(|letter| game.append(letter)).unwrap();
+        assert!(absurdle.submit(&mut game).is_err());
+        assert_eq!(absurdle.candidates().len(), left);
+    }
+}
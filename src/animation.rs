@@ -0,0 +1,226 @@
+use std::collections::VecDeque;
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use crate::colors::{Theme, ThemeColor};
+use crate::ui::set_button_label;
+use crate::{Board, Position};
+
+// how long each animation takes, in seconds.
+const POP_SECONDS: f32 = 0.1;
+const FLIP_SECONDS: f32 = 0.4;
+const SHAKE_SECONDS: f32 = 0.35;
+const BOUNCE_SECONDS: f32 = 0.5;
+// time between one tile of a row starting to flip (or bounce) and the next.
+const FLIP_GAP_SECONDS: f32 = 0.25;
+const BOUNCE_GAP_SECONDS: f32 = 0.1;
+// how far tiles move, before the board is scaled.
+const POP_GROWTH: f32 = 0.15;
+const SHAKE_DISTANCE: f32 = 10.0;
+const BOUNCE_HEIGHT: f32 = 20.0;
+
+pub struct AnimationPlugin;
+
+/// Turns every tile animation off, tiles change straight away.
+#[derive(Default)]
+pub struct ReducedMotion(pub bool);
+
+/// true while a submitted row is still flipping over.
+/// keys are ignored until it's done, so the next guess can't start early.
+#[derive(Default)]
+pub struct Revealing(pub bool);
+
+/// turns reduced motion on and off.
+#[derive(Component)]
+pub struct MotionButton;
+
+#[derive(Debug, Clone, Copy)]
+enum AnimationKind {
+    // grows a little and back when a letter is typed in.
+    Pop,
+    // turns over and shows the color of its score halfway.
+    // the tile only takes the color on then, so a theme change doesn't give it away early.
+    Flip(ThemeColor),
+    // wiggles sideways when the guess was turned down.
+    Shake,
+    // jumps up and down once the answer is found.
+    Bounce,
+}
+
+impl AnimationKind {
+    fn seconds(&self) -> f32 {
+        match self {
+            AnimationKind::Pop => POP_SECONDS,
+            AnimationKind::Flip(_) => FLIP_SECONDS,
+            AnimationKind::Shake => SHAKE_SECONDS,
+            AnimationKind::Bounce => BOUNCE_SECONDS,
+        }
+    }
+}
+
+/// Animations a tile plays one after another, every tile has one.
+/// The tile is moved around its resting place on the board, which is worked out
+/// from its Position, so replacing an animation halfway never leaves it out of place.
+#[derive(Debug, Default, Component)]
+pub struct TileAnimation {
+    // kind and how long to wait before it starts, counted from when the one before ends.
+    queue: VecDeque<(AnimationKind, f32)>,
+    // seconds since the front of the queue started waiting.
+    elapsed: f32,
+}
+
+impl TileAnimation {
+    fn new(kind: AnimationKind, delay: f32) -> Self {
+        TileAnimation {
+            queue: VecDeque::from([(kind, delay)]),
+            elapsed: 0.0,
+        }
+    }
+
+    fn then(mut self, kind: AnimationKind, delay: f32) -> Self {
+        self.queue.push_back((kind, delay));
+        self
+    }
+
+    /// a letter was typed into the tile.
+    pub fn pop() -> Self {
+        TileAnimation::new(AnimationKind::Pop, 0.0)
+    }
+
+    /// the guess was turned down.
+    pub fn shake() -> Self {
+        TileAnimation::new(AnimationKind::Shake, 0.0)
+    }
+
+    /// the tile in column of a submitted row turns over to show the color of role.
+    /// tiles flip left to right, a row that found the answer bounces once they all have.
+    pub fn reveal(role: ThemeColor, column: u8, columns: u8, solved: bool) -> Self {
+        let column = f32::from(column);
+        let animation = TileAnimation::new(AnimationKind::Flip(role), column * FLIP_GAP_SECONDS);
+        if !solved {
+            return animation;
+        }
+        // waits for the tiles to the right to finish flipping.
+        let flips_left = (f32::from(columns) - 1.0 - column) * FLIP_GAP_SECONDS;
+        animation.then(AnimationKind::Bounce, flips_left + column * BOUNCE_GAP_SECONDS)
+    }
+
+    /// true while the tile still has to flip over.
+    pub fn is_revealing(&self) -> bool {
+        self.queue
+            .iter()
+            .any(|(kind, _)| matches!(kind, AnimationKind::Flip(_)))
+    }
+
+    /// true once every animation has played.
+    pub fn is_finished(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReducedMotion>()
+            .init_resource::<Revealing>()
+            .add_system(tile_animation_system)
+            .add_system(motion_button_system)
+            .add_system(motion_text_system);
+    }
+}
+
+/// moves every animated tile for this frame and drops the animations that are done.
+fn tile_animation_system(
+    time: Res<Time>,
+    theme: Res<Theme>,
+    boards: Query<&Board>,
+    mut tiles: Query<(&Position, &mut TileAnimation, &mut Transform, &mut Sprite, &mut ThemeColor)>,
+    mut revealing: ResMut<Revealing>,
+) {
+    let mut still_revealing = false;
+    for (position, mut animation, mut transform, mut sprite, mut tile_role) in tiles.iter_mut() {
+        if animation.queue.is_empty() {
+            continue;
+        }
+        let board = match boards.iter().find(|board| board.index == position.board) {
+            Some(board) => board,
+            // the board is being replaced, the tile goes with it.
+            None => continue,
+        };
+        let rest = board.tile_translation(*position);
+        animation.elapsed += time.delta_seconds();
+        // offset from the resting place, and how much to stretch the tile.
+        let mut offset = Vec2::ZERO;
+        let mut stretch = Vec2::ONE;
+        while let Some((kind, delay)) = animation.queue.front().copied() {
+            let progress = (animation.elapsed - delay) / kind.seconds();
+            if progress < 0.0 {
+                break;
+            }
+            if progress >= 1.0 {
+                if let AnimationKind::Flip(role) = kind {
+                    *tile_role = role;
+                    sprite.color = theme.color(role);
+                }
+                animation.elapsed -= delay + kind.seconds();
+                animation.queue.pop_front();
+                continue;
+            }
+            match kind {
+                AnimationKind::Pop => stretch *= 1.0 + POP_GROWTH * (PI * progress).sin(),
+                AnimationKind::Flip(role) => {
+                    // edge on halfway, that's when the color changes.
+                    stretch.y *= (PI * progress).cos().abs();
+                    if progress >= 0.5 {
+                        *tile_role = role;
+                        sprite.color = theme.color(role);
+                    }
+                }
+                AnimationKind::Shake => {
+                    offset.x = SHAKE_DISTANCE * (6.0 * PI * progress).sin() * (1.0 - progress);
+                }
+                AnimationKind::Bounce => offset.y = BOUNCE_HEIGHT * (PI * progress).sin(),
+            }
+            break;
+        }
+        transform.translation.x = rest.x + offset.x * board.scale;
+        transform.translation.y = rest.y + offset.y * board.scale;
+        transform.scale.x = board.scale * stretch.x;
+        transform.scale.y = board.scale * stretch.y;
+        still_revealing |= animation.is_revealing();
+    }
+    if revealing.0 != still_revealing {
+        revealing.0 = still_revealing;
+    }
+}
+
+/// flips the reduced motion setting, animations already playing finish.
+fn motion_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<MotionButton>)>,
+    mut reduced_motion: ResMut<ReducedMotion>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            reduced_motion.0 = !reduced_motion.0;
+        }
+    }
+}
+
+/// keeps the motion button showing the setting.
+fn motion_text_system(
+    reduced_motion: Res<ReducedMotion>,
+    button_query: Query<&Children, With<MotionButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !reduced_motion.is_changed() {
+        return;
+    }
+    let label = if reduced_motion.0 {
+        "Motion: Off"
+    } else {
+        "Motion: On"
+    };
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, label);
+    }
+}
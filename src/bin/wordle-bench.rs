@@ -0,0 +1,220 @@
+//! Plays every answer in the word list with the solver and reports how it did.
+//! No window needed, runs anywhere the terminal version does.
+//!
+//! Prints the average number of guesses, the failures and the guess histogram to stderr,
+//! and the result of every game as JSON (or CSV) to stdout.
+//! The games are split over every core, the results don't depend on how many there are.
+//!
+//! `--length <n>` plays words with 4 to 7 letters instead of 5.
+//! `--hard` plays in hard mode.
+//! `--opener <word>` always starts with that word instead of the solver's pick.
+//! `--format <json|csv>` picks the output format, JSON by default.
+//! `--output <file>` writes the results to a file instead of stdout.
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::thread;
+
+use serde::Serialize;
+use wordle::cli::parse_option;
+use wordle::config::GameConfig;
+use wordle::game::*;
+use wordle::solver::{PatternTable, Solver};
+use wordle::words::*;
+
+/// how one answer went.
+#[derive(Debug, Serialize)]
+struct GameResult {
+    answer: String,
+    solved: bool,
+    // every guess, the last one is the answer when it was solved.
+    guesses: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    word_length: usize,
+    max_guesses: usize,
+    hard_mode: bool,
+    // None when the solver picked the first guess too.
+    opener: Option<String>,
+    games: usize,
+    failures: usize,
+    // guesses per solved game.
+    average_guesses: f64,
+    // number of guesses -> games solved with that many.
+    histogram: BTreeMap<usize, usize>,
+    results: Vec<GameResult>,
+}
+
+enum Format {
+    Json,
+    Csv,
+}
+
+fn main() -> io::Result<()> {
+    let hard_mode = std::env::args().any(|arg| arg == "--hard");
+    let config = match parse_option("--length") {
+        Some(length) => match length.parse().ok().and_then(GameConfig::for_word_length) {
+            Some(config) => config,
+            None => exit_with_usage(&format!("no word list for --length {}, try 4, 5, 6 or 7", length)),
+        },
+        None => GameConfig::default(),
+    };
+    let format = match parse_option("--format").as_deref() {
+        None | Some("json") => Format::Json,
+        Some("csv") => Format::Csv,
+        Some(format) => exit_with_usage(&format!("unknown --format {}, try json or csv", format)),
+    };
+    let word_list = WordList::for_length(config.word_length)
+        .expect("expect a word list for every configurable length");
+    let opener = parse_option("--opener").map(|opener| opener.to_ascii_uppercase());
+    if let Some(opener) = &opener {
+        if opener.len() != config.word_length || !word_list.is_allowed(opener) {
+            exit_with_usage(&format!("{} isn't a {} letter word in the word list", opener, config.word_length));
+        }
+    }
+
+    let results = play_all(config, &word_list, hard_mode, opener.as_deref());
+    let report = report(config, hard_mode, opener, results);
+    eprintln!(
+        "{} games, {} failed, {:.3} guesses on average",
+        report.games,
+        report.failures,
+        report.average_guesses,
+    );
+    for (guesses, games) in &report.histogram {
+        eprintln!("{}: {}", guesses, games);
+    }
+
+    let mut out: Box<dyn Write> = match parse_option("--output") {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, &report)?;
+            writeln!(out)?;
+        }
+        Format::Csv => write_csv(&mut out, &report.results)?,
+    }
+    out.flush()
+}
+
+/// plays every answer, split over every core.
+/// results come back in word list order whatever the split was.
+fn play_all(
+    config: GameConfig,
+    word_list: &WordList,
+    hard_mode: bool,
+    opener: Option<&str>,
+) -> Vec<GameResult> {
+    // built once up front, every thread shares it.
+    PatternTable::for_words(word_list);
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let answers = word_list.answers();
+    let chunk_size = answers.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let handles: Vec<_> = answers
+            .chunks(chunk_size.max(1))
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|answer| play(config, word_list, answer, hard_mode, opener))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("expect a game not to panic"))
+            .collect()
+    })
+}
+
+/// lets the solver play one game to the end.
+fn play(
+    config: GameConfig,
+    word_list: &WordList,
+    answer: &str,
+    hard_mode: bool,
+    opener: Option<&str>,
+) -> GameResult {
+    let mut game = Game::new(config, word_list.clone(), answer);
+    game.set_hard_mode(hard_mode);
+    let mut solver = Solver::new(word_list);
+    solver.set_hard_mode(hard_mode);
+    while !game.is_over() {
+        let guess = match (game.rows().is_empty(), opener) {
+            (true, Some(opener)) => opener.to_string(),
+            _ => match solver.best_guess() {
+                Some(guess) => guess.to_string(),
+                // can't happen with the game's own word list, counted as a failure if it does.
+                None => break,
+            },
+        };
+        let submitted = guess
+            .chars()
+            .try_for_each(|letter| game.append(letter))
+            .and_then(|_| game.submit().map(|row| solver.add(row)));
+        if submitted.is_err() {
+            break;
+        }
+    }
+    GameResult {
+        answer: answer.to_string(),
+        solved: game.status() == GameStatus::Won,
+        guesses: game.guesses(),
+    }
+}
+
+fn report(
+    config: GameConfig,
+    hard_mode: bool,
+    opener: Option<String>,
+    results: Vec<GameResult>,
+) -> Report {
+    let mut histogram: BTreeMap<usize, usize> = (1..=config.max_guesses).map(|guesses| (guesses, 0)).collect();
+    let mut total_guesses = 0;
+    for result in results.iter().filter(|result| result.solved) {
+        *histogram.entry(result.guesses.len()).or_default() += 1;
+        total_guesses += result.guesses.len();
+    }
+    let solved = results.iter().filter(|result| result.solved).count();
+    Report {
+        word_length: config.word_length,
+        max_guesses: config.max_guesses,
+        hard_mode,
+        opener,
+        games: results.len(),
+        failures: results.len() - solved,
+        average_guesses: if solved == 0 { 0.0 } else { total_guesses as f64 / solved as f64 },
+        histogram,
+        results,
+    }
+}
+
+/// one line per game, the guesses are separated by spaces.
+/// words are only ever letters, so nothing needs quoting.
+fn write_csv(out: &mut impl Write, results: &[GameResult]) -> io::Result<()> {
+    writeln!(out, "answer,solved,guess_count,guesses")?;
+    for result in results {
+        writeln!(
+            out,
+            "{},{},{},{}",
+            result.answer,
+            result.solved,
+            result.guesses.len(),
+            result.guesses.join(" "),
+        )?;
+    }
+    Ok(())
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}", message);
+    eprintln!("usage: wordle-bench [--length <n>] [--hard] [--opener <word>] [--format <json|csv>] [--output <file>]");
+    std::process::exit(2);
+}
@@ -0,0 +1,300 @@
+//! Plays wordle in the terminal, no window or GPU needed.
+//!
+//! Type letters, Backspace to delete, Enter to submit. Ctrl-C or Ctrl-D quits.
+//! `--seed <n>` plays the answer picked by that seed.
+//! `--length <n>` plays words with 4 to 7 letters instead of 5.
+//! `--hard` turns on hard mode: revealed hints have to be used in every guess.
+//! `--absurdle` plays without a fixed answer, every guess keeps as many words in play as it can.
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+
+use wordle::absurdle::Absurdle;
+use wordle::cli::parse_option;
+use wordle::config::GameConfig;
+use wordle::game::*;
+use wordle::keyboard::*;
+use wordle::multi::MultiGame;
+use wordle::scoring::LetterScore;
+use wordle::words::*;
+
+// ANSI escape codes.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+const RESET: &str = "\x1b[0m";
+const BLACK_ON_GREEN: &str = "\x1b[30;42m";
+const BLACK_ON_YELLOW: &str = "\x1b[30;43m";
+const WHITE_ON_GRAY: &str = "\x1b[97;100m";
+const BLACK_ON_WHITE: &str = "\x1b[30;47m";
+
+// bytes read from stdin.
+const ESCAPE: u8 = 0x1b;
+const CTRL_C: u8 = 0x03;
+const CTRL_D: u8 = 0x04;
+const BACKSPACE: u8 = 0x7f;
+const CTRL_H: u8 = 0x08;
+
+enum Key {
+    Letter(char),
+    Delete,
+    Submit,
+    Quit,
+}
+
+fn main() -> io::Result<()> {
+    let seed = parse_option("--seed").and_then(|seed| seed.parse().ok());
+    let hard_mode = std::env::args().any(|arg| arg == "--hard");
+    let absurdle_mode = std::env::args().any(|arg| arg == "--absurdle");
+    let config = match parse_option("--length") {
+        Some(length) => match length.parse().ok().and_then(GameConfig::for_word_length) {
+            Some(config) => config,
+            None => {
+                eprintln!("no word list for --length {}, try 4, 5, 6 or 7", length);
+                std::process::exit(2);
+            }
+        },
+        None => GameConfig::default(),
+    };
+    let word_list = WordList::for_length(config.word_length)
+        .expect("expect a word list for every configurable length");
+    let _raw_mode = RawMode::enable();
+    let mut stdin = io::stdin().lock();
+    let (mut game, mut absurdle) = new_round(config, &word_list, seed.unwrap_or_else(random_seed), absurdle_mode);
+    game.set_hard_mode(hard_mode);
+    let mut message = String::new();
+    loop {
+        draw(&game, absurdle.as_ref(), &message)?;
+        let key = match read_key(&mut stdin)? {
+            Some(key) => key,
+            // stdin was closed.
+            None => break,
+        };
+        message.clear();
+        if game.is_over() {
+            // ENTER plays another round.
+            match key {
+                Key::Submit => {
+                    (game, absurdle) = new_round(config, &word_list, random_seed(), absurdle_mode);
+                    game.set_hard_mode(hard_mode);
+                }
+                Key::Quit => break,
+                _ => (),
+            }
+            continue;
+        }
+        let result = match key {
+            Key::Letter(letter) => game.append(letter),
+            Key::Delete => game.delete(),
+            Key::Submit => submit(&mut game, absurdle.as_mut()),
+            Key::Quit => break,
+        };
+        if let Err(error) = result {
+            message = error.to_string();
+        }
+    }
+    println!();
+    Ok(())
+}
+
+/// a single board game, with the answer picked by seed or left open for absurdle.
+fn new_round(
+    config: GameConfig,
+    word_list: &WordList,
+    seed: u64,
+    absurdle_mode: bool,
+) -> (MultiGame, Option<Absurdle>) {
+    if absurdle_mode {
+        let absurdle = Absurdle::new(word_list);
+        (absurdle.new_game(config, word_list.clone()), Some(absurdle))
+    } else {
+        (MultiGame::with_seed(config, word_list.clone(), seed), None)
+    }
+}
+
+/// submits the guess, through absurdle when it picks the answer.
+fn submit(game: &mut MultiGame, absurdle: Option<&mut Absurdle>) -> Result<(), GuessError> {
+    match absurdle {
+        Some(absurdle) => absurdle.submit(game),
+        None => game.submit(),
+    }
+}
+
+/// reads bytes until one of them is a key the game uses.
+/// returns None once stdin is closed.
+fn read_key(stdin: &mut impl Read) -> io::Result<Option<Key>> {
+    let mut byte = [0u8; 1];
+    // a byte read after an ESC that turned out not to start an escape sequence.
+    let mut pending = None;
+    loop {
+        let next = match pending.take() {
+            Some(next) => next,
+            None => {
+                if stdin.read(&mut byte)? == 0 {
+                    return Ok(None);
+                }
+                byte[0]
+            }
+        };
+        let key = match next {
+            b'\n' | b'\r' => Key::Submit,
+            BACKSPACE | CTRL_H => Key::Delete,
+            CTRL_C | CTRL_D => Key::Quit,
+            ESCAPE => {
+                // arrow keys and friends send ESC [ ... so their letters
+                // don't end up in the guess. the key after a lone ESC still counts.
+                pending = skip_escape_sequence(stdin)?;
+                continue;
+            }
+            b if b.is_ascii_alphabetic() => Key::Letter(b.to_ascii_uppercase() as char),
+            _ => continue,
+        };
+        return Ok(Some(key));
+    }
+}
+
+/// skips the rest of an escape sequence, the ESC was already read.
+/// returns the byte after the ESC when it doesn't start a sequence.
+fn skip_escape_sequence(stdin: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+    if stdin.read(&mut byte)? == 0 {
+        return Ok(None);
+    }
+    if !matches!(byte[0], b'[' | b'O') {
+        return Ok(Some(byte[0]));
+    }
+    // parameters until the final byte, which is in @..~
+    while stdin.read(&mut byte)? != 0 {
+        if (0x40..=0x7e).contains(&byte[0]) {
+            break;
+        }
+    }
+    Ok(None)
+}
+
+/// redraws the whole screen: board, message and keyboard.
+fn draw(game: &MultiGame, absurdle: Option<&Absurdle>, message: &str) -> io::Result<()> {
+    let board = &game.boards()[0];
+    let mut out = io::stdout().lock();
+    write!(out, "{}", CLEAR_SCREEN)?;
+    // \r\n so lines start at the left edge even when the terminal doesn't translate \n.
+    let name = if absurdle.is_some() { "ABSURDLE" } else { "WORDLE" };
+    let title = if game.is_hard_mode() { format!("{} (hard mode)", name) } else { name.to_string() };
+    write!(out, "  {}\r\n\r\n", title)?;
+    let config = game.config();
+    for row in 0..config.max_guesses {
+        write!(out, "  ")?;
+        match board.rows().get(row) {
+            Some(scored) => {
+                for (letter, score) in scored.word.chars().zip(&scored.scores) {
+                    write_cell(&mut out, letter, Some(*score))?;
+                }
+            }
+            None => {
+                // the row being typed in shows the guess so far, later rows are empty.
+                let typed: Vec<char> = if row == game.guess_index() {
+                    game.current_guess().chars().collect()
+                } else {
+                    Vec::new()
+                };
+                for column in 0..config.word_length {
+                    write_cell(&mut out, typed.get(column).copied().unwrap_or(' '), None)?;
+                }
+            }
+        }
+        write!(out, "\r\n")?;
+    }
+    write!(out, "\r\n  {}\r\n\r\n", status_line(game, absurdle, message))?;
+    for (indent, keys) in KEYBOARD_ROWS.iter().enumerate() {
+        write!(out, "{}", " ".repeat(2 + indent))?;
+        for key in &KEYBOARD_LETTERS[keys.clone()] {
+            let score = key
+                .chars()
+                .next()
+                .filter(|_| key.len() == 1)
+                .and_then(|letter| board.letter_states().get(letter));
+            write!(out, "{} {} {} ", key_color(score), key, RESET)?;
+        }
+        write!(out, "\r\n")?;
+    }
+    out.flush()
+}
+
+fn write_cell(out: &mut impl Write, letter: char, score: Option<LetterScore>) -> io::Result<()> {
+    let color = match score {
+        Some(score) => key_color(Some(score)),
+        None => BLACK_ON_WHITE,
+    };
+    write!(out, "{} {} {} ", color, letter, RESET)
+}
+
+/// same colors as the board: green right spot, yellow wrong spot, gray not in word.
+fn key_color(score: Option<LetterScore>) -> &'static str {
+    match score {
+        Some(LetterScore::Correct) => BLACK_ON_GREEN,
+        Some(LetterScore::Present) => BLACK_ON_YELLOW,
+        Some(LetterScore::Absent) => WHITE_ON_GRAY,
+        None => RESET,
+    }
+}
+
+fn status_line(game: &MultiGame, absurdle: Option<&Absurdle>, message: &str) -> String {
+    let board = &game.boards()[0];
+    let still_possible = absurdle.map_or(1, |absurdle| absurdle.candidates().len());
+    match game.status() {
+        GameStatus::Won => format!(
+            "Solved in {}/{}! Enter to play again, Ctrl-C to quit.",
+            board.rows().len(),
+            game.config().max_guesses,
+        ),
+        GameStatus::Lost if still_possible > 1 => format!(
+            "{} words were still possible, like {}. Enter to play again, Ctrl-C to quit.",
+            still_possible,
+            board.answer(),
+        ),
+        GameStatus::Lost => format!(
+            "The word was {}. Enter to play again, Ctrl-C to quit.",
+            board.answer(),
+        ),
+        GameStatus::InProgress if message.is_empty() && absurdle.is_some() && !board.rows().is_empty() => {
+            format!("{} words left", still_possible)
+        }
+        GameStatus::InProgress => message.to_string(),
+    }
+}
+
+/// puts the terminal in a mode where keys are read as soon as they are pressed
+/// and aren't echoed. The old settings are put back when this is dropped.
+///
+/// uses `stty` so there are no extra dependencies. When stdin isn't a
+/// terminal (e.g. piped input) nothing changes and input is read as is.
+struct RawMode {
+    saved: Option<String>,
+}
+
+impl RawMode {
+    fn enable() -> Self {
+        // -isig so Ctrl-C comes through as a key and the settings get restored.
+        let saved = stty(&["-g"])
+            .filter(|_| stty(&["-icanon", "-echo", "-isig", "min", "1"]).is_some());
+        RawMode { saved }
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        if let Some(saved) = &self.saved {
+            stty(&[saved.trim()]);
+        }
+    }
+}
+
+/// runs stty against the terminal on stdin, returns its output when it worked.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use wordle::keyboard::{BACK_KEY, ENTER_KEY};
+use wordle::solver::{PatternTable, Solver};
+use wordle::words::WordList;
+
+use crate::animation::Revealing;
+use crate::ui::{show_message, MessageText};
+use crate::{GameContext, GuessUpdateAction, GuessUpdateEvent, RunState};
+
+// time between keys typed by the bot.
+const BOT_KEY_SECONDS: f32 = 0.15;
+
+pub struct BotUiPlugin;
+
+/// starts and stops the bot, lives in the menu row next to the title.
+#[derive(Component)]
+pub struct BotButton;
+
+/// The solver playing the game on screen.
+/// It types its guesses a key at a time, through the same events as the keyboard.
+struct Bot {
+    playing: bool,
+    timer: Timer,
+    // keys of the guess being typed in.
+    keys: VecDeque<String>,
+}
+
+impl Default for Bot {
+    fn default() -> Self {
+        Bot {
+            playing: false,
+            timer: Timer::from_seconds(BOT_KEY_SECONDS, true),
+            keys: VecDeque::new(),
+        }
+    }
+}
+
+impl Plugin for BotUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Bot>()
+            .add_system(bot_button_system)
+            .add_system_set(
+                SystemSet::on_update(RunState::Playing)
+                    .with_system(bot_play_system)
+            )
+            .add_system_set(
+                SystemSet::on_enter(RunState::Playing)
+                    .with_system(stop_bot)
+                    .with_system(warm_pattern_table.after("game_reset"))
+            )
+            .add_system_set(
+                SystemSet::on_enter(RunState::GameOver)
+                    .with_system(stop_bot)
+            );
+    }
+}
+
+/// starts the bot on the game being played, or stops it when it's already playing.
+fn bot_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<BotButton>)>,
+    mut bot: ResMut<Bot>,
+    game_context: Res<GameContext>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        if game_context.replaying {
+            show_message(&mut message_query, "The bot can't play a replay.");
+            continue;
+        }
+        if game_context.game.is_over() {
+            show_message(&mut message_query, "The game is over, the bot plays the next one.");
+            continue;
+        }
+        bot.playing = !bot.playing;
+        bot.keys.clear();
+        bot.timer.reset();
+        show_message(&mut message_query, if bot.playing {
+            "The bot is playing."
+        } else {
+            "The bot stopped."
+        });
+    }
+}
+
+/// types the next key of the bot's guess.
+/// a new guess is picked once the last one was submitted.
+fn bot_play_system(
+    time: Res<Time>,
+    revealing: Res<Revealing>,
+    mut bot: ResMut<Bot>,
+    mut game_context: ResMut<GameContext>,
+    mut guess_writer: EventWriter<GuessUpdateEvent>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    if !bot.playing || !bot.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    // waits for its last guess to be revealed, like a player has to.
+    // it's stopped once the game is over.
+    if revealing.0 || game_context.game_over_pending {
+        return;
+    }
+    if bot.keys.is_empty() {
+        let game = &game_context.game;
+        // the bot works on the first board that isn't solved yet.
+        let guess = game
+            .boards()
+            .iter()
+            .find(|board| !board.is_over())
+            .and_then(|board| Solver::for_game(board).best_guess().map(String::from));
+        let guess = match guess {
+            // the guess is still typed in, so the last one was turned down.
+            Some(guess) if guess != game.current_guess() => guess,
+            _ => {
+                bot.playing = false;
+                show_message(&mut message_query, "The bot is out of ideas.");
+                return;
+            }
+        };
+        let typed = game.current_guess().len();
+        bot.keys.extend((0..typed).map(|_| BACK_KEY.to_string()));
+        bot.keys.extend(guess.chars().map(String::from));
+        bot.keys.push_back(ENTER_KEY.to_string());
+        // a game the bot helped with doesn't go in the stats.
+        game_context.bot_played = true;
+    }
+    if let Some(key) = bot.keys.pop_front() {
+        if let Ok(action) = GuessUpdateAction::try_from(key.clone()) {
+            guess_writer.send(GuessUpdateEvent { action, key });
+        }
+    }
+}
+
+fn stop_bot(mut bot: ResMut<Bot>) {
+    bot.playing = false;
+    bot.keys.clear();
+}
+
+/// scores the word list in the background, so the bot's first guess doesn't freeze the game.
+/// nothing to do when it was scored for an earlier game.
+fn warm_pattern_table(word_list: Res<WordList>) {
+    if PatternTable::is_cached(&word_list) {
+        return;
+    }
+    let word_list = word_list.clone();
+    std::thread::spawn(move || PatternTable::for_words(&word_list));
+}
@@ -0,0 +1,14 @@
+//! Command line options, shared by every binary.
+use std::env;
+
+/// the value given after an option, e.g. `--seed 42` gives Some("42").
+/// None when the option isn't there or nothing comes after it.
+pub fn parse_option(name: &str) -> Option<String> {
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next();
+        }
+    }
+    None
+}
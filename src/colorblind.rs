@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+
+use crate::colors::ThemeColor;
+use crate::ui::set_button_label;
+use crate::{Position, TILE_SIZE};
+
+pub struct ColorblindPlugin;
+
+/// Orange and blue instead of green and yellow, and a marker on every letter
+/// that's in the word so the result can be read without the colors:
+/// a bar under the letter in the right spot, a dot in the corner when it's in the wrong spot.
+#[derive(Default)]
+pub struct ColorblindMode(pub bool);
+
+/// turns colorblind mode on and off.
+#[derive(Component)]
+pub struct ColorblindButton;
+
+/// shape drawn on a scored tile, a child of the tile.
+#[derive(Component)]
+pub struct TileMarker;
+
+/// shape drawn on a keyboard key, a child of each part of the key.
+#[derive(Component)]
+pub struct KeyMarker;
+
+/// size and middle of a marker, as a fraction of the tile or key it's on.
+/// y goes down from the top, like it does for ui nodes.
+struct MarkerShape {
+    size: Vec2,
+    center: Vec2,
+}
+
+/// the marker for a letter colored with role, None when it doesn't get one.
+fn marker_shape(role: ThemeColor) -> Option<MarkerShape> {
+    match role {
+        ThemeColor::RightSpot => Some(MarkerShape {
+            size: Vec2::new(0.6, 0.08),
+            center: Vec2::new(0.5, 0.86),
+        }),
+        ThemeColor::WrongSpot => Some(MarkerShape {
+            size: Vec2::new(0.16, 0.16),
+            center: Vec2::new(0.82, 0.18),
+        }),
+        _ => None,
+    }
+}
+
+impl Plugin for ColorblindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorblindMode>()
+            .add_system(colorblind_button_system)
+            .add_system(colorblind_text_system)
+            .add_system(tile_marker_system);
+    }
+}
+
+/// shows the marker of a key part that's colored with role.
+/// role - None for a letter that hasn't been guessed yet.
+pub fn key_marker_style(style: &mut Style, role: Option<ThemeColor>, colorblind: bool) {
+    let shape = match role.and_then(marker_shape) {
+        Some(shape) if colorblind => shape,
+        _ => {
+            style.display = Display::None;
+            return;
+        }
+    };
+    style.display = Display::Flex;
+    style.size = Size::new(
+        Val::Percent(shape.size.x * 100.0),
+        Val::Percent(shape.size.y * 100.0),
+    );
+    style.position = Rect {
+        left: Val::Percent((shape.center.x - shape.size.x / 2.0) * 100.0),
+        right: Val::Auto,
+        top: Val::Percent((shape.center.y - shape.size.y / 2.0) * 100.0),
+        bottom: Val::Auto,
+    };
+}
+
+/// shows the marker of every tile that was scored, when colorblind mode is on.
+/// tiles change color halfway through their flip, the marker comes with it.
+fn tile_marker_system(
+    colorblind: Res<ColorblindMode>,
+    tile_query: Query<(&ThemeColor, ChangeTrackers<ThemeColor>, &Children), With<Position>>,
+    mut marker_query: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<TileMarker>>,
+) {
+    for (role, tracker, children) in tile_query.iter() {
+        if !colorblind.is_changed() && !tracker.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            let (mut sprite, mut transform, mut visibility) = match marker_query.get_mut(*child) {
+                Ok(marker) => marker,
+                Err(_) => continue,
+            };
+            let shape = match marker_shape(*role) {
+                Some(shape) if colorblind.0 => shape,
+                _ => {
+                    visibility.is_visible = false;
+                    continue;
+                }
+            };
+            visibility.is_visible = true;
+            sprite.custom_size = Some(shape.size * TILE_SIZE);
+            // sprites are placed from the middle of the tile, y going up.
+            transform.translation.x = (shape.center.x - 0.5) * TILE_SIZE;
+            transform.translation.y = (0.5 - shape.center.y) * TILE_SIZE;
+        }
+    }
+}
+
+fn colorblind_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ColorblindButton>)>,
+    mut colorblind: ResMut<ColorblindMode>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Clicked {
+            colorblind.0 = !colorblind.0;
+        }
+    }
+}
+
+/// keeps the colorblind button showing the setting.
+fn colorblind_text_system(
+    colorblind: Res<ColorblindMode>,
+    button_query: Query<&Children, With<ColorblindButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !colorblind.is_changed() {
+        return;
+    }
+    let label = if colorblind.0 {
+        "Colorblind: On"
+    } else {
+        "Colorblind: Off"
+    };
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, label);
+    }
+}
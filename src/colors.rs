@@ -1,35 +1,278 @@
-use bevy::prelude::Color;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
-pub struct Materials {
+use bevy::prelude::{Color, Component};
+use serde::Deserialize;
+
+use wordle::scoring::LetterScore;
+
+/// Every color the game draws with.
+/// The built in themes are below, more can be loaded from RON files, see Theme::load.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    // behind everything, the window itself.
+    pub background: Color,
+    // title and message text.
+    pub text: Color,
     pub board: Color,
     pub tile_placeholder: Color,
     pub tile: Color,
-    pub none: Color,
+    pub tile_letter: Color,
+    // behind the on screen keyboard.
+    pub keyboard: Color,
+    pub key: Color,
+    pub key_letter: Color,
+    // letter is in word, right spot.
+    pub right_spot: Color,
+    // letter is in word, but wrong spot.
+    pub wrong_spot: Color,
+    // letter is not in word.
+    pub not_in_word: Color,
+    // background of panels drawn over the board, e.g. stats.
+    pub panel: Color,
+    pub panel_text: Color,
 }
 
-pub const MATERIALS: Materials = Materials {
-    board: Color::rgb(1.0, 1.0, 1.0),
-    tile_placeholder: Color::rgb(0.5, 0.5, 0.5),
-    tile: Color::rgb(1.0, 1.0, 1.0),
-    none: Color::NONE,
-};
+/// What a color is used for, one per color in a Theme.
+/// Entities with one of these are recolored when the theme changes.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Component)]
+pub enum ThemeColor {
+    Background,
+    Text,
+    Board,
+    TilePlaceholder,
+    Tile,
+    TileLetter,
+    Keyboard,
+    Key,
+    KeyLetter,
+    RightSpot,
+    WrongSpot,
+    NotInWord,
+    Panel,
+    PanelText,
+}
 
-pub struct KeyboardMaterials {
-    pub kb_btn_background: Color,
-    pub kb_btn_letter: Color,
-    // letter is in word, but wrong spot.
-    pub kb_btn_background_wrong_spot: Color,
-    // letter is in word, right spot.
-    pub kb_btn_background_right_spot: Color,
-    // letter is not in word.
-    pub kb_btn_background_not_in_word: Color,
+impl ThemeColor {
+    pub const ALL: [ThemeColor; 14] = [
+        ThemeColor::Background,
+        ThemeColor::Text,
+        ThemeColor::Board,
+        ThemeColor::TilePlaceholder,
+        ThemeColor::Tile,
+        ThemeColor::TileLetter,
+        ThemeColor::Keyboard,
+        ThemeColor::Key,
+        ThemeColor::KeyLetter,
+        ThemeColor::RightSpot,
+        ThemeColor::WrongSpot,
+        ThemeColor::NotInWord,
+        ThemeColor::Panel,
+        ThemeColor::PanelText,
+    ];
+
+    /// name of the color in a theme file.
+    pub fn key(&self) -> &'static str {
+        match self {
+            ThemeColor::Background => "background",
+            ThemeColor::Text => "text",
+            ThemeColor::Board => "board",
+            ThemeColor::TilePlaceholder => "tile_placeholder",
+            ThemeColor::Tile => "tile",
+            ThemeColor::TileLetter => "tile_letter",
+            ThemeColor::Keyboard => "keyboard",
+            ThemeColor::Key => "key",
+            ThemeColor::KeyLetter => "key_letter",
+            ThemeColor::RightSpot => "right_spot",
+            ThemeColor::WrongSpot => "wrong_spot",
+            ThemeColor::NotInWord => "not_in_word",
+            ThemeColor::Panel => "panel",
+            ThemeColor::PanelText => "panel_text",
+        }
+    }
+
+    /// color used to show how a letter scored.
+    pub fn for_score(score: LetterScore) -> Self {
+        match score {
+            LetterScore::Correct => ThemeColor::RightSpot,
+            LetterScore::Present => ThemeColor::WrongSpot,
+            LetterScore::Absent => ThemeColor::NotInWord,
+        }
+    }
 }
 
-pub const KEYBOARD_MATERIALS: KeyboardMaterials =
-    KeyboardMaterials {
-        kb_btn_background: Color::rgb(0.9, 0.9, 0.9),
-        kb_btn_letter: Color::BLACK,
-        kb_btn_background_wrong_spot: Color::rgb(1.0,1.0,0.0),
-        kb_btn_background_right_spot: Color::rgb(0.0,1.0,0.0),
-        kb_btn_background_not_in_word: Color::rgb(0.9,0.9,0.9),
-    };
\ No newline at end of file
+/// A theme as it's written in a file. Colors are hex strings, e.g. "#6aaa64".
+///
+/// EX:
+/// (
+///     name: "Forest",
+///     base: "dark",
+///     colors: {
+///         "right_spot": "#2e7d32",
+///         "wrong_spot": "#c0a000",
+///     },
+/// )
+///
+/// colors that aren't in the file are taken from the base theme, light when there's no base.
+#[derive(Debug, Deserialize)]
+struct ThemeFile {
+    name: String,
+    #[serde(default = "default_base")]
+    base: String,
+    #[serde(default)]
+    colors: HashMap<String, String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::light()
+    }
+}
+
+impl Theme {
+    /// the colors the game always had.
+    pub fn light() -> Self {
+        Theme {
+            name: "Light".to_string(),
+            background: Color::rgb(0.4, 0.4, 0.4),
+            text: Color::WHITE,
+            board: Color::rgb(1.0, 1.0, 1.0),
+            tile_placeholder: Color::rgb(0.5, 0.5, 0.5),
+            tile: Color::rgb(1.0, 1.0, 1.0),
+            tile_letter: Color::BLACK,
+            keyboard: Color::WHITE,
+            key: Color::rgb(0.9, 0.9, 0.9),
+            key_letter: Color::BLACK,
+            right_spot: Color::rgb(0.0, 1.0, 0.0),
+            wrong_spot: Color::rgb(1.0, 1.0, 0.0),
+            not_in_word: Color::rgb(0.6, 0.6, 0.6),
+            panel: Color::rgb(0.15, 0.15, 0.15),
+            panel_text: Color::WHITE,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Theme {
+            name: "Dark".to_string(),
+            background: Color::rgb(0.07, 0.07, 0.08),
+            text: Color::rgb(0.9, 0.9, 0.9),
+            board: Color::rgb(0.12, 0.12, 0.13),
+            tile_placeholder: Color::rgb(0.23, 0.23, 0.24),
+            tile: Color::rgb(0.12, 0.12, 0.13),
+            tile_letter: Color::WHITE,
+            keyboard: Color::rgb(0.07, 0.07, 0.08),
+            key: Color::rgb(0.5, 0.51, 0.52),
+            key_letter: Color::WHITE,
+            right_spot: Color::rgb(0.33, 0.55, 0.31),
+            wrong_spot: Color::rgb(0.71, 0.62, 0.23),
+            not_in_word: Color::rgb(0.23, 0.23, 0.24),
+            panel: Color::rgb(0.2, 0.2, 0.21),
+            panel_text: Color::WHITE,
+        }
+    }
+
+    /// black and white with the brightest colors there are, for low vision.
+    pub fn high_contrast() -> Self {
+        Theme {
+            name: "High Contrast".to_string(),
+            background: Color::BLACK,
+            text: Color::WHITE,
+            board: Color::BLACK,
+            tile_placeholder: Color::WHITE,
+            tile: Color::BLACK,
+            tile_letter: Color::WHITE,
+            keyboard: Color::BLACK,
+            key: Color::WHITE,
+            key_letter: Color::BLACK,
+            right_spot: Color::rgb(0.0, 0.8, 0.0),
+            wrong_spot: Color::rgb(1.0, 0.85, 0.0),
+            not_in_word: Color::rgb(0.3, 0.3, 0.3),
+            panel: Color::BLACK,
+            panel_text: Color::WHITE,
+        }
+    }
+
+    /// the theme with orange and blue in place of green and yellow,
+    /// they are easier to tell apart with most kinds of color blindness.
+    pub fn colorblind(mut self) -> Self {
+        self.right_spot = Color::rgb(0.96, 0.47, 0.23);
+        self.wrong_spot = Color::rgb(0.52, 0.75, 0.98);
+        self
+    }
+
+    /// every theme that comes with the game, the first one is the default.
+    pub fn built_in() -> Vec<Self> {
+        vec![Theme::light(), Theme::dark(), Theme::high_contrast()]
+    }
+
+    /// reads a theme from a RON file.
+    /// returns the theme and the keys that were missing (or not a color) and were
+    /// filled in from the base theme, so they can be reported.
+    pub fn load(path: &Path) -> Result<(Self, Vec<String>), String> {
+        let text = fs::read_to_string(path).map_err(|error| error.to_string())?;
+        let file: ThemeFile = ron::from_str(&text).map_err(|error| error.to_string())?;
+        let mut theme = Theme::built_in()
+            .into_iter()
+            .find(|theme| theme.name.eq_ignore_ascii_case(&file.base))
+            .ok_or_else(|| format!("there is no built in theme called {}", file.base))?;
+        theme.name = file.name;
+        let mut missing = Vec::new();
+        for role in ThemeColor::ALL {
+            let color = file.colors.get(role.key()).and_then(|hex| parse_color(hex));
+            match color {
+                Some(color) => *theme.color_mut(role) = color,
+                None => missing.push(role.key().to_string()),
+            }
+        }
+        Ok((theme, missing))
+    }
+
+    pub fn color(&self, role: ThemeColor) -> Color {
+        match role {
+            ThemeColor::Background => self.background,
+            ThemeColor::Text => self.text,
+            ThemeColor::Board => self.board,
+            ThemeColor::TilePlaceholder => self.tile_placeholder,
+            ThemeColor::Tile => self.tile,
+            ThemeColor::TileLetter => self.tile_letter,
+            ThemeColor::Keyboard => self.keyboard,
+            ThemeColor::Key => self.key,
+            ThemeColor::KeyLetter => self.key_letter,
+            ThemeColor::RightSpot => self.right_spot,
+            ThemeColor::WrongSpot => self.wrong_spot,
+            ThemeColor::NotInWord => self.not_in_word,
+            ThemeColor::Panel => self.panel,
+            ThemeColor::PanelText => self.panel_text,
+        }
+    }
+
+    fn color_mut(&mut self, role: ThemeColor) -> &mut Color {
+        match role {
+            ThemeColor::Background => &mut self.background,
+            ThemeColor::Text => &mut self.text,
+            ThemeColor::Board => &mut self.board,
+            ThemeColor::TilePlaceholder => &mut self.tile_placeholder,
+            ThemeColor::Tile => &mut self.tile,
+            ThemeColor::TileLetter => &mut self.tile_letter,
+            ThemeColor::Keyboard => &mut self.keyboard,
+            ThemeColor::Key => &mut self.key,
+            ThemeColor::KeyLetter => &mut self.key_letter,
+            ThemeColor::RightSpot => &mut self.right_spot,
+            ThemeColor::WrongSpot => &mut self.wrong_spot,
+            ThemeColor::NotInWord => &mut self.not_in_word,
+            ThemeColor::Panel => &mut self.panel,
+            ThemeColor::PanelText => &mut self.panel_text,
+        }
+    }
+}
+
+fn default_base() -> String {
+    "light".to_string()
+}
+
+/// "#rrggbb" or "#rrggbbaa", the # is optional.
+fn parse_color(hex: &str) -> Option<Color> {
+    Color::hex(hex.trim_start_matches('#')).ok()
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::{MAX_GUESSES, WORD_LENGTH};
+
+/// word lengths there is a word list for.
+pub const WORD_LENGTHS: [usize; 4] = [4, 5, 6, 7];
+/// how many boards can be played at once. 1 is a normal game.
+pub const BOARD_COUNTS: [usize; 4] = [1, 2, 4, 8];
+
+/// How big a game is: letters per word, guesses before the game is lost
+/// and how many boards every guess is played on.
+/// Everything that draws or checks a guess reads these instead of assuming 5 and 6.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub struct GameConfig {
+    pub word_length: usize,
+    pub max_guesses: usize,
+    // saves from before multiple boards were playable have a single board.
+    #[serde(default = "one_board")]
+    pub boards: usize,
+}
+
+fn one_board() -> usize {
+    1
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            word_length: WORD_LENGTH,
+            max_guesses: MAX_GUESSES,
+            boards: 1,
+        }
+    }
+}
+
+impl GameConfig {
+    /// config for a word length and number of boards,
+    /// None when there is no word list for that length or the number of boards isn't supported.
+    /// longer words get more guesses, one more guess than letters.
+    /// every extra board adds a guess too, e.g. 7 guesses for two 5 letter boards.
+    pub fn new(word_length: usize, boards: usize) -> Option<Self> {
+        if !WORD_LENGTHS.contains(&word_length) || !BOARD_COUNTS.contains(&boards) {
+            return None;
+        }
+        Some(GameConfig {
+            word_length,
+            max_guesses: word_length + boards,
+            boards,
+        })
+    }
+
+    /// config for a word length on a single board.
+    pub fn for_word_length(word_length: usize) -> Option<Self> {
+        GameConfig::new(word_length, 1)
+    }
+
+    /// the next word length, going back to the shortest after the longest.
+    pub fn next_word_length(&self) -> Self {
+        let word_length = next(&WORD_LENGTHS, self.word_length);
+        GameConfig::new(word_length, self.boards)
+            .expect("expect every word length in the list to have a config")
+    }
+
+    /// the next number of boards, going back to 1 after the most.
+    pub fn next_board_count(&self) -> Self {
+        let boards = next(&BOARD_COUNTS, self.boards);
+        GameConfig::new(self.word_length, boards)
+            .expect("expect every board count in the list to have a config")
+    }
+}
+
+/// the value after current in the list, wrapping around.
+/// a value that isn't in the list starts over at the first one.
+fn next(values: &[usize], current: usize) -> usize {
+    let index = values
+        .iter()
+        .position(|value| *value == current)
+        .map_or(0, |index| (index + 1) % values.len());
+    values[index]
+}
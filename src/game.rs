@@ -0,0 +1,346 @@
+use crate::config::GameConfig;
+use crate::scoring::{is_solved, score_guess, LetterScore, LetterStates};
+use crate::words::WordList;
+
+/// letters in a word, unless the GameConfig says otherwise.
+pub const WORD_LENGTH: usize = 5;
+/// guesses the player gets before the game is lost, unless the GameConfig says otherwise.
+pub const MAX_GUESSES: usize = 6;
+
+/// A guess that was accepted, along with how each letter scored.
+#[derive(Debug, Clone)]
+pub struct ScoredGuess {
+    pub word: String,
+    pub scores: Vec<LetterScore>,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// Why the guess couldn't be updated.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum GuessError {
+    // no more changes once the game has been won or lost.
+    GameOver,
+    // the guess already has every letter.
+    GuessFull,
+    // nothing to delete.
+    GuessEmpty,
+    // only letters can be added to a guess.
+    NotALetter,
+    // not enough letters to submit. holds how many letters are required.
+    TooShort(usize),
+    // the guess isn't in the dictionary.
+    NotInWordList,
+    // hard mode: a letter found in the right spot has to stay there.
+    // position is 0 based.
+    MissingLetterAt { position: usize, letter: char },
+    // hard mode: a letter found in the word has to be used again.
+    MissingLetter(char),
+}
+
+impl std::fmt::Display for GuessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuessError::GameOver => write!(f, "The game is over."),
+            GuessError::GuessFull => write!(f, "Guess is full."),
+            GuessError::GuessEmpty => write!(f, "Nothing to delete."),
+            GuessError::NotALetter => write!(f, "Only letters can be guessed."),
+            GuessError::TooShort(required) => write!(f, "{} characters required to submit guess.", required),
+            GuessError::NotInWordList => write!(f, "Not in word list"),
+            GuessError::MissingLetterAt { position, letter } => {
+                write!(f, "{} letter must be {}", ordinal(position + 1), letter)
+            }
+            GuessError::MissingLetter(letter) => write!(f, "Guess must contain {}", letter),
+        }
+    }
+}
+
+/// 1 -> 1st, 2 -> 2nd, ...
+pub fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+impl std::error::Error for GuessError {}
+
+/// A single game of wordle, without anything to draw it.
+/// Frontends feed it key presses and draw what it reports back.
+#[derive(Debug, Clone)]
+pub struct Game {
+    config: GameConfig,
+    words: WordList,
+    answer: String,
+    // guesses that were accepted, in the order they were made.
+    rows: Vec<ScoredGuess>,
+    // guess being typed in.
+    current: String,
+    letter_states: LetterStates,
+    status: GameStatus,
+    // every guess has to use the hints revealed so far.
+    hard_mode: bool,
+}
+
+impl Game {
+    /// starts a game. The answer should be upper case, like the word list.
+    /// the word list should have words of the configured length.
+    pub fn new(config: GameConfig, words: WordList, answer: &str) -> Self {
+        debug_assert_eq!(config.word_length, words.word_length());
+        Game {
+            config,
+            words,
+            answer: answer.to_string(),
+            rows: Vec::new(),
+            current: String::new(),
+            letter_states: LetterStates::default(),
+            status: GameStatus::InProgress,
+            hard_mode: false,
+        }
+    }
+
+    /// rebuilds a game from guesses that were already made.
+    /// guesses that can't be submitted anymore are skipped.
+    pub fn from_guesses(
+        config: GameConfig,
+        words: WordList,
+        answer: &str,
+        guesses: &[String],
+        hard_mode: bool,
+    ) -> Self {
+        let mut game = Game::new(config, words, answer);
+        game.hard_mode = hard_mode;
+        for guess in guesses {
+            game.current.clear();
+            if guess.chars().try_for_each(|letter| game.append(letter)).is_ok() {
+                let _ = game.submit();
+            }
+        }
+        game.current.clear();
+        game
+    }
+
+    /// starts a game with the answer picked from the word list by seed.
+    pub fn with_seed(config: GameConfig, words: WordList, seed: u64) -> Self {
+        let answer = words.pick_answer(seed).to_string();
+        Game::new(config, words, &answer)
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.config
+    }
+
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    /// swaps the answer for another one.
+    /// only makes sense when the new answer scores every earlier guess
+    /// the same way the old one did, like the candidates in absurdle do.
+    pub fn replace_answer(&mut self, answer: &str) {
+        self.answer = answer.to_string();
+    }
+
+    pub fn words(&self) -> &WordList {
+        &self.words
+    }
+
+    /// every accepted guess so far.
+    pub fn rows(&self) -> &[ScoredGuess] {
+        &self.rows
+    }
+
+    /// the words of every accepted guess.
+    pub fn guesses(&self) -> Vec<String> {
+        self.rows.iter().map(|row| row.word.clone()).collect()
+    }
+
+    pub fn current_guess(&self) -> &str {
+        &self.current
+    }
+
+    /// row the current guess is typed into. 0 is the first guess.
+    pub fn guess_index(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn letter_states(&self) -> &LetterStates {
+        &self.letter_states
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status != GameStatus::InProgress
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// turns hard mode on or off.
+    /// only allowed before the first guess, returns false if it was too late.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) -> bool {
+        if !self.rows.is_empty() {
+            return self.hard_mode == hard_mode;
+        }
+        self.hard_mode = hard_mode;
+        true
+    }
+
+    /// adds a letter to the end of the current guess.
+    pub fn append(&mut self, letter: char) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        if !letter.is_ascii_alphabetic() {
+            return Err(GuessError::NotALetter);
+        }
+        if self.current.len() >= self.config.word_length {
+            return Err(GuessError::GuessFull);
+        }
+        self.current.push(letter.to_ascii_uppercase());
+        Ok(())
+    }
+
+    /// removes the last letter of the current guess.
+    /// accepted guesses are locked in and can't be changed.
+    pub fn delete(&mut self) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        self.current.pop().map(|_| ()).ok_or(GuessError::GuessEmpty)
+    }
+
+    /// scores the current guess and moves on to the next row.
+    /// the guess is left as it was when it can't be submitted.
+    pub fn submit(&mut self) -> Result<&ScoredGuess, GuessError> {
+        self.check_guess(&self.current)?;
+        let word = std::mem::take(&mut self.current);
+        let scores = score_guess(&word, &self.answer);
+        self.letter_states.update(&word, &scores);
+        if is_solved(&scores) {
+            self.status = GameStatus::Won;
+        } else if self.rows.len() + 1 >= self.config.max_guesses {
+            // that was the last row. out of guesses.
+            self.status = GameStatus::Lost;
+        }
+        self.rows.push(ScoredGuess { word, scores });
+        Ok(self.rows.last().expect("expect the guess that was just pushed"))
+    }
+
+    /// the reason a guess would be turned down by submit, without submitting it.
+    pub fn check_guess(&self, guess: &str) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        if guess.len() < self.config.word_length {
+            return Err(GuessError::TooShort(self.config.word_length));
+        }
+        if !self.words.is_allowed(guess) {
+            return Err(GuessError::NotInWordList);
+        }
+        if self.hard_mode {
+            self.check_hints_used(guess)?;
+        }
+        Ok(())
+    }
+
+    /// hard mode rule: green letters stay where they are and
+    /// yellow letters show up somewhere in the guess.
+    fn check_hints_used(&self, guess: &str) -> Result<(), GuessError> {
+        let guess: Vec<char> = guess.chars().collect();
+        for row in &self.rows {
+            for (position, (letter, score)) in row.word.chars().zip(&row.scores).enumerate() {
+                if *score == LetterScore::Correct && guess.get(position) != Some(&letter) {
+                    return Err(GuessError::MissingLetterAt { position, letter });
+                }
+            }
+        }
+        for row in &self.rows {
+            for letter in row.word.chars() {
+                // a letter revealed twice (e.g. two yellow Es) has to be used twice.
+                let revealed = row.word
+                    .chars()
+                    .zip(&row.scores)
+                    .filter(|(l, score)| *l == letter && **score != LetterScore::Absent)
+                    .count();
+                let used = guess.iter().filter(|l| **l == letter).count();
+                if used < revealed {
+                    return Err(GuessError::MissingLetter(letter));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_in_progress(&self) -> Result<(), GuessError> {
+        match self.status {
+            GameStatus::InProgress => Ok(()),
+            _ => Err(GuessError::GameOver),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a hard mode game with the answer, after the guesses were played.
+    fn hard_game(answer: &str, guesses: &[&str]) -> Game {
+        let guesses: Vec<String> = guesses.iter().map(|guess| guess.to_string()).collect();
+        let game = Game::from_guesses(GameConfig::default(), WordList::default(), answer, &guesses, true);
+        assert_eq!(game.rows().len(), guesses.len(), "expect every guess to be accepted");
+        game
+    }
+
+    #[test]
+    fn green_letters_have_to_stay_put() {
+        // A, I, D and E are green.
+        let game = hard_game("ABIDE", &["ASIDE"]);
+        assert_eq!(
+            game.check_hints_used("CIDER"),
+            Err(GuessError::MissingLetterAt { position: 0, letter: 'A' }),
+        );
+        assert_eq!(
+            game.check_hints_used("ABODE"),
+            Err(GuessError::MissingLetterAt { position: 2, letter: 'I' }),
+        );
+        assert_eq!(game.check_hints_used("ABIDE"), Ok(()));
+    }
+
+    #[test]
+    fn yellow_letters_have_to_be_used_again() {
+        // E and D are yellow, the second E is gray.
+        let game = hard_game("ABIDE", &["SPEED"]);
+        assert_eq!(game.check_hints_used("CRANE"), Err(GuessError::MissingLetter('D')));
+        // anywhere will do, even the spot it was yellow in.
+        assert_eq!(game.check_hints_used("DIODE"), Ok(()));
+        assert_eq!(game.check_hints_used("CIDER"), Ok(()));
+    }
+
+    #[test]
+    fn a_letter_found_twice_has_to_be_used_twice() {
+        // the first E is yellow and the last one green, THEME has two.
+        let game = hard_game("THEME", &["EERIE"]);
+        assert_eq!(game.check_hints_used("CRANE"), Err(GuessError::MissingLetter('E')));
+        assert_eq!(game.check_hints_used("THREE"), Ok(()));
+    }
+
+    #[test]
+    fn clues_only_matter_in_hard_mode() {
+        let mut game = Game::new(GameConfig::default(), WordList::default(), "ABIDE");
+        for guess in ["SPEED", "CRANE"] {
+            guess.chars().try_for_each(|letter| game.append(letter)).unwrap();
+            assert!(game.submit().is_ok(), "expect {} to be accepted", guess);
+        }
+        // too late to turn it on.
+        assert!(!game.set_hard_mode(true));
+        assert!(!game.is_hard_mode());
+    }
+}
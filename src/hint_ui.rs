@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use wordle::game::ordinal;
+use wordle::hints::{reveal_letter, suggest_word};
+
+use crate::colors::{Theme, ThemeColor};
+use crate::ui::{show_message, spawn_menu_button, MessageText};
+use crate::{FontSpec, GameContext, RunState};
+
+pub struct HintUiPlugin;
+
+/// opens the hint choices, lives in the menu row next to the title.
+#[derive(Component)]
+pub struct HintButton;
+/// root of the hint choices. despawning it closes them.
+#[derive(Component)]
+struct HintMenu;
+#[derive(Component)]
+struct RevealLetterButton;
+#[derive(Component)]
+struct SuggestWordButton;
+
+impl Plugin for HintUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(hint_button_system)
+            .add_system(hint_choice_system)
+            .add_system_set(
+                SystemSet::on_enter(RunState::Playing)
+                    .with_system(close_hint_menu)
+            );
+    }
+}
+
+/// opens the hint choices, or closes them when they are already open.
+fn hint_button_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<HintButton>)>,
+    menu_query: Query<Entity, With<HintMenu>>,
+    font_spec: Res<FontSpec>,
+    theme: Res<Theme>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        if menu_query.is_empty() {
+            spawn_hint_menu(&mut commands, &font_spec, &theme);
+        }
+        for entity in menu_query.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn close_hint_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<HintMenu>>,
+) {
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// gives the hint that was picked and closes the choices.
+fn hint_choice_system(
+    mut commands: Commands,
+    reveal_query: Query<&Interaction, (Changed<Interaction>, With<RevealLetterButton>)>,
+    suggest_query: Query<&Interaction, (Changed<Interaction>, With<SuggestWordButton>)>,
+    menu_query: Query<Entity, With<HintMenu>>,
+    mut game_context: ResMut<GameContext>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    let reveal = reveal_query.iter().any(|i| *i == Interaction::Clicked);
+    let suggest = suggest_query.iter().any(|i| *i == Interaction::Clicked);
+    if !reveal && !suggest {
+        return;
+    }
+    for entity in menu_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let message = give_hint(&mut game_context, reveal);
+    show_message(&mut message_query, &message);
+}
+
+/// works out the hint and counts it, returns what to tell the player.
+/// reveal - true for a letter of the answer, false for a word that fits the clues.
+fn give_hint(game_context: &mut GameContext, reveal: bool) -> String {
+    if game_context.replaying {
+        return "No hints while watching a replay.".to_string();
+    }
+    if game_context.absurdle.is_some() {
+        return "No hints in Absurdle, there is no answer yet.".to_string();
+    }
+    let game = &game_context.game;
+    // hints are for the first board that isn't solved yet.
+    let (index, board) = match game.boards().iter().enumerate().find(|(_, board)| !board.is_over()) {
+        Some(board) => board,
+        None => return "No hints, the game is over.".to_string(),
+    };
+    let on_board = match game.boards().len() {
+        1 => String::new(),
+        _ => format!(" on board {}", index + 1),
+    };
+    let mut revealed = None;
+    let hint = if reveal {
+        // letters already given aren't given again, the player has them.
+        let given: Vec<usize> = game_context
+            .revealed
+            .iter()
+            .filter(|(on, _)| *on == index)
+            .map(|(_, position)| *position)
+            .collect();
+        reveal_letter(board, &given).map(|(position, letter)| {
+            revealed = Some((index, position));
+            format!("Hint: {} letter is {}{}", ordinal(position + 1), letter, on_board)
+        })
+    } else {
+        suggest_word(board).map(|word| format!("Hint: try {}{}", word, on_board))
+    };
+    let hint = match hint {
+        Some(hint) => hint,
+        None => return "No hint left to give.".to_string(),
+    };
+    game_context.hints += 1;
+    game_context.revealed.extend(revealed);
+    // the hint count is part of the save, so it can't be dodged by restarting.
+    if let Err(error) = game_context.save_progress() {
+        warn!("couldn't save the game: {}", error);
+    }
+    hint
+}
+
+fn spawn_hint_menu(commands: &mut Commands, font_spec: &FontSpec, theme: &Theme) {
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    left: Val::Percent(25.0),
+                    right: Val::Auto,
+                    top: Val::Percent(12.0),
+                    bottom: Val::Auto,
+                },
+                size: Size::new(Val::Percent(50.0), Val::Auto),
+                justify_content: JustifyContent::Center,
+                padding: Rect::all(Val::Px(20.0)),
+                ..Default::default()
+            },
+            color: UiColor(theme.panel),
+            ..Default::default()
+        })
+        .insert(ThemeColor::Panel)
+        .with_children(|menu| {
+            spawn_menu_button(menu, font_spec, theme, "Reveal a letter", RevealLetterButton);
+            spawn_menu_button(menu, font_spec, theme, "Suggest a word", SuggestWordButton);
+        })
+        .insert(HintMenu);
+}
@@ -0,0 +1,204 @@
+use crate::game::{Game, ScoredGuess};
+use crate::scoring::LetterScore;
+
+/// Everything the scored guesses say about the answer, boiled down so checking
+/// a word is a handful of array lookups. Cheap enough to run against the whole
+/// dictionary every time it's needed.
+#[derive(Debug, Clone)]
+pub struct Clues {
+    // letter known to be in each spot.
+    fixed: Vec<Option<u8>>,
+    // letters known not to be in each spot, one bit per letter.
+    not_at: Vec<u32>,
+    // fewest and most copies of each letter the answer can have.
+    min_count: [u8; 26],
+    max_count: [u8; 26],
+}
+
+impl Clues {
+    /// clues from guesses that were already scored.
+    /// words are upper case, like the word list.
+    pub fn new(rows: &[ScoredGuess], word_length: usize) -> Self {
+        let mut clues = Clues {
+            fixed: vec![None; word_length],
+            not_at: vec![0; word_length],
+            min_count: [0; 26],
+            max_count: [u8::MAX; 26],
+        };
+        for row in rows {
+            clues.add(row);
+        }
+        clues
+    }
+
+    /// narrows the clues down with one more scored guess.
+    pub fn add(&mut self, row: &ScoredGuess) {
+        let mut found = [0u8; 26];
+        let mut absent = [false; 26];
+        for (position, (letter, score)) in row.word.bytes().zip(&row.scores).enumerate() {
+            let index = letter_index(letter);
+            match score {
+                LetterScore::Correct => {
+                    self.fixed[position] = Some(letter);
+                    found[index] += 1;
+                }
+                LetterScore::Present => {
+                    self.not_at[position] |= 1 << index;
+                    found[index] += 1;
+                }
+                LetterScore::Absent => {
+                    self.not_at[position] |= 1 << index;
+                    absent[index] = true;
+                }
+            }
+        }
+        for index in 0..26 {
+            self.min_count[index] = self.min_count[index].max(found[index]);
+            // a gray copy means every copy in the answer was already found.
+            if absent[index] {
+                self.max_count[index] = self.max_count[index].min(found[index]);
+            }
+        }
+    }
+
+    /// true when the word could still be the answer.
+    pub fn fits(&self, word: &str) -> bool {
+        if word.len() != self.fixed.len() {
+            return false;
+        }
+        let mut counts = [0u8; 26];
+        for (position, letter) in word.bytes().enumerate() {
+            if !letter.is_ascii_uppercase() {
+                return false;
+            }
+            let index = letter_index(letter);
+            if matches!(self.fixed[position], Some(fixed) if fixed != letter)
+                || self.not_at[position] & (1 << index) != 0
+            {
+                return false;
+            }
+            counts[index] += 1;
+        }
+        counts
+            .iter()
+            .zip(self.min_count.iter().zip(&self.max_count))
+            .all(|(count, (min, max))| count >= min && count <= max)
+    }
+}
+
+/// 0 for A, 25 for Z.
+fn letter_index(letter: u8) -> usize {
+    usize::from(letter - b'A')
+}
+
+/// a letter of the answer that no guess has put in the right spot yet,
+/// as (position, letter). The leftmost one is picked.
+/// revealed - positions already given as hints, they aren't given again.
+pub fn reveal_letter(game: &Game, revealed: &[usize]) -> Option<(usize, char)> {
+    game.answer()
+        .chars()
+        .enumerate()
+        .find(|(position, _)| {
+            !revealed.contains(position)
+                && !game.rows().iter().any(|row| row.scores[*position] == LetterScore::Correct)
+        })
+}
+
+/// a word from the dictionary that fits every clue so far and hasn't been guessed.
+/// answers are tried first, they are the more common words.
+pub fn suggest_word(game: &Game) -> Option<String> {
+    let clues = Clues::new(game.rows(), game.config().word_length);
+    let words = game.words();
+    let guessed = game.guesses();
+    words
+        .answers()
+        .iter()
+        .chain(words.allowed_guesses())
+        .find(|word| !guessed.contains(word) && clues.fits(word))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::scoring::score_guess;
+    use crate::words::WordList;
+
+    fn clues(answer: &str, guesses: &[&str]) -> Clues {
+        let rows: Vec<ScoredGuess> = guesses
+            .iter()
+            .map(|guess| ScoredGuess {
+                word: guess.to_string(),
+                scores: score_guess(guess, answer),
+            })
+            .collect();
+        Clues::new(&rows, answer.len())
+    }
+
+    #[test]
+    fn the_answer_always_fits() {
+        let clues = clues("THEME", &["CRANE", "EERIE", "THREE"]);
+        assert!(clues.fits("THEME"));
+    }
+
+    #[test]
+    fn green_yellow_and_gray_letters_rule_words_out() {
+        // C green, A yellow, R N E gray.
+        let clues = clues("CLOAK", &["CRANE"]);
+        assert!(clues.fits("CLOAK"));
+        assert!(clues.fits("COMMA"));
+        // no C up front.
+        assert!(!clues.fits("SHACK"));
+        // no A.
+        assert!(!clues.fits("CLOTH"));
+        // A where it was yellow.
+        assert!(!clues.fits("COAST"));
+        // a gray E.
+        assert!(!clues.fits("CLEAT"));
+    }
+
+    #[test]
+    fn letter_counts_come_from_repeated_letters() {
+        // SPEED against ABIDE: one E yellow and one gray, so exactly one E.
+        let clues = clues("ABIDE", &["SPEED"]);
+        assert!(clues.fits("ABIDE"));
+        assert!(!clues.fits("EIDER"));
+        // the yellow E has to be there somewhere.
+        assert!(!clues.fits("AUDIO"));
+    }
+
+    #[test]
+    fn words_of_the_wrong_length_never_fit() {
+        let clues = clues("ABIDE", &[]);
+        assert!(!clues.fits("ABIDES"));
+        assert!(!clues.fits("abide"));
+    }
+
+    #[test]
+    fn reveals_the_first_letter_not_found_yet() {
+        let mut game = Game::new(GameConfig::default(), WordList::default(), "ABIDE");
+        assert_eq!(reveal_letter(&game, &[]), Some((0, 'A')));
+        // A, I, D and E are green.
+        "ASIDE".chars().try_for_each(|letter| game.append(letter)).unwrap();
+        game.submit().unwrap();
+        assert_eq!(reveal_letter(&game, &[]), Some((1, 'B')));
+    }
+
+    #[test]
+    fn revealed_letters_are_not_given_again() {
+        let game = Game::new(GameConfig::default(), WordList::default(), "ABIDE");
+        assert_eq!(reveal_letter(&game, &[0]), Some((1, 'B')));
+        assert_eq!(reveal_letter(&game, &[0, 1, 2, 3, 4]), None);
+    }
+
+    #[test]
+    fn suggested_words_fit_the_clues() {
+        let mut game = Game::new(GameConfig::default(), WordList::default(), "ABIDE");
+        "SPEED".chars().try_for_each(|letter| game.append(letter)).unwrap();
+        game.submit().unwrap();
+        let word = suggest_word(&game).expect("expect a word to fit");
+        assert!(Clues::new(game.rows(), 5).fits(&word));
+        assert_ne!(word, "SPEED");
+    }
+}
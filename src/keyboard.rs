@@ -0,0 +1,12 @@
+/// Keys on the on screen keyboard, QWERTY order, top row first.
+pub const KEYBOARD_LETTERS: [&str; 28] = [
+    "Q", "W", "E", "R", "T", "Y", "U", "I", "O", "P",
+    "A", "S", "D", "F", "G", "H", "J", "K", "L",
+    "ENTER", "Z", "X", "C", "V", "B", "N", "M", "<-"
+    ];
+
+/// the keys of each keyboard row, top row first.
+pub const KEYBOARD_ROWS: [std::ops::Range<usize>; 3] = [0..10, 10..19, 19..28];
+
+pub const ENTER_KEY: &str = "ENTER";
+pub const BACK_KEY: &str = "<-";
@@ -0,0 +1,19 @@
+//! Wordle game rules with no window attached.
+//!
+//! The Bevy app in main.rs is one frontend for these rules,
+//! anything else (tools, bots, tests) can drive a Game the same way.
+pub mod absurdle;
+pub mod cli;
+pub mod config;
+pub mod game;
+pub mod hints;
+pub mod keyboard;
+pub mod mode;
+pub mod multi;
+pub mod replay;
+pub mod scoring;
+pub mod share;
+pub mod solver;
+pub mod stats;
+pub mod storage;
+pub mod words;
@@ -1,9 +1,35 @@
 use bevy::{prelude::*};
 use itertools::Itertools;
-use std::{env, cmp::Ordering};
+use std::{env, io, cmp::Ordering};
+use wordle::absurdle::Absurdle;
+use wordle::cli::parse_option;
+use wordle::config::GameConfig;
+use wordle::game::*;
+use wordle::keyboard::{BACK_KEY, ENTER_KEY};
+use wordle::mode::*;
+use wordle::multi::MultiGame;
+use wordle::replay::Replay;
+use wordle::scoring::is_solved;
+use wordle::stats::Stats;
+use wordle::storage::{DailyRecord, GameRecord, SavedGame};
+use wordle::words::*;
 
+mod animation;
+use animation::*;
+mod bot_ui;
+use bot_ui::*;
+mod colorblind;
+use colorblind::*;
 mod colors;
 use colors::*;
+mod hint_ui;
+use hint_ui::*;
+mod replay_ui;
+use replay_ui::*;
+mod stats_ui;
+use stats_ui::*;
+mod theme_ui;
+use theme_ui::*;
 mod ui;
 use ui::*;
 
@@ -17,18 +43,72 @@ const COLUMN_SPACER: f32 = 5.0;
 const COLUMN_PADDING: f32 = 20.0;
 const ROW_SPACER: f32 = 6.0;
 const ROW_PADDING: f32 = 20.0;
-// max of 5 guesses. range 0.=5
-const LAST_GUESS_INDEX: u8 = 5;
+// height of the 6 row board. taller boards are scaled down to this
+// so they don't run into the title or the keyboard.
+const MAX_BOARD_HEIGHT: f32 = 6.0 * TILE_PLACEHOLDER_SIZE + 5.0 * ROW_SPACER + ROW_PADDING * 2.0;
+// width the boards can take up when there are several of them side by side.
+const MAX_BOARDS_WIDTH: f32 = 1200.0;
+// space between boards when there are several.
+const BOARD_SPACER: f32 = 10.0;
+// boards sit 100 units up to make room for the keyboard.
+const BOARDS_CENTER_Y: f32 = 100.0;
 
 #[derive(Debug, Component)]
 struct Board {
+    // which of the game's boards this is, 0 when there is only one.
+    index: usize,
     columns: u8,
     rows: u8,
     height: f32,
     width: f32,
+    // how much the board and its tiles are scaled to fit the window.
+    scale: f32,
+    // middle of the board in the window.
+    center: Vec2,
 }
 
 impl Board {
+    /// one board per board in the game, laid out in a grid,
+    /// sized for the word length and number of guesses being played.
+    fn layout(config: &GameConfig) -> Vec<Self> {
+        let board = Board::new(config.word_length as u8, config.max_guesses as u8);
+        let (grid_columns, grid_rows) = board_grid(config);
+        let scale = board.grid_scale(grid_columns, grid_rows);
+        (0..config.boards)
+            .map(|index| {
+                // grid row 0 is at the top.
+                let column = (index % grid_columns) as f32 - (grid_columns - 1) as f32 / 2.0;
+                let row = (index / grid_columns) as f32 - (grid_rows - 1) as f32 / 2.0;
+                Board {
+                    index,
+                    scale,
+                    center: Vec2::new(
+                        column * (board.width + BOARD_SPACER) * scale,
+                        BOARDS_CENTER_Y - row * (board.height + BOARD_SPACER) * scale,
+                    ),
+                    ..Board::new(board.columns, board.rows)
+                }
+            })
+            .collect()
+    }
+
+    /// scale that fits a grid of boards this size in the window, never bigger than 1.
+    fn grid_scale(&self, grid_columns: usize, grid_rows: usize) -> f32 {
+        let width = grid_columns as f32 * (self.width + BOARD_SPACER) - BOARD_SPACER;
+        let height = grid_rows as f32 * (self.height + BOARD_SPACER) - BOARD_SPACER;
+        (MAX_BOARDS_WIDTH / width)
+            .min(MAX_BOARD_HEIGHT / height)
+            .min(1.0)
+    }
+
+    /// where a tile on this board goes in the window.
+    fn tile_translation(&self, pos: Position) -> Vec2 {
+        self.center + Vec2::new(
+            self.column_position_to_physical(pos.x),
+            self.row_position_to_physical(pos.y),
+        ) * self.scale
+    }
+
     fn new(columns: u8, rows:u8) -> Self {
         // calculating width and height using
         // tile_background_size because that's the size of the whole tile.
@@ -47,10 +127,13 @@ impl Board {
             + Board::get_spacers(rows) * ROW_SPACER
             + ROW_PADDING * 2.0;
         Board {
+            index: 0,
             columns,
             rows,
             height,
             width,
+            scale: (MAX_BOARD_HEIGHT / height).min(1.0),
+            center: Vec2::new(0.0, BOARDS_CENTER_Y),
         }
     }
 
@@ -90,16 +173,32 @@ impl Board {
     Debug, Eq, PartialEq, Hash, Copy, Clone, Component
 )]
 struct Position {
+    // index of the board the tile is on.
+    board: usize,
     x: u8,
     y: u8,
 }
 
 impl std::fmt::Display for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "x:{}, y:{}", self.x, self.y)
+        write!(f, "board:{}, x:{}, y:{}", self.board, self.x, self.y)
     }
 }
 
+/// columns and rows of boards, picked so the boards are drawn as big as they can be.
+/// e.g. 2 boards side by side.
+fn board_grid(config: &GameConfig) -> (usize, usize) {
+    let board = Board::new(config.word_length as u8, config.max_guesses as u8);
+    (1..=config.boards)
+        // only grids the boards fill completely.
+        .filter(|grid_rows| config.boards / grid_rows * grid_rows == config.boards)
+        .map(|grid_rows| (config.boards / grid_rows, grid_rows))
+        .max_by(|a, b| {
+            board.grid_scale(a.0, a.1).total_cmp(&board.grid_scale(b.0, b.1))
+        })
+        .unwrap_or((1, 1))
+}
+
 
 #[derive(Component)]
 struct TileText;
@@ -165,44 +264,284 @@ impl TryFrom<String> for GuessUpdateAction {
     }
 }
 
-#[derive(Default)]
 struct GameContext {
-    // A game of wordle can take up to 5 guesses.
-    // 1 guess is being maintained at a time.
-    guess_collection: Vec<String>,
-    score: u32,
+    // the rules live in the library, this app only draws the game.
+    // a normal game is a MultiGame with one board.
+    game: MultiGame,
+    // daily, practice or absurdle. takes effect when the next game starts.
+    mode: GameMode,
+    // absurdle only: the words the answer could still be.
+    // the game is scored against a stand-in answer picked from these.
+    absurdle: Option<Absurdle>,
+    // daily puzzle number, None in practice mode.
+    puzzle: Option<u32>,
+    // seed the answer was picked with.
+    seed: u64,
+    // hard mode setting. a game that already has guesses keeps what it started with.
+    hard_mode: bool,
+    // hints given this game.
+    hints: u32,
+    // letters given as hints this game, as (board, position).
+    revealed: Vec<(usize, usize)>,
+    // true once the bot made a guess this game.
+    bot_played: bool,
+    // game left unfinished last time, picked up when the first game starts.
+    resume: Option<SavedGame>,
+    // replay given on the command line, set up instead of the first game.
+    replay: Option<Replay>,
+    // true while a replay is being watched. nothing is saved or added to the stats.
+    replaying: bool,
+    // false until the finished game has been added to the stats.
+    // a daily that was finished on an earlier launch is already in them.
+    result_recorded: bool,
+    // true once the game is over but the last row is still flipping over,
+    // see finish_game.
+    game_over_pending: bool,
 }
 
 impl GameContext {
-    /// returns the index to guess_collection for the last guess.
-    fn get_guess_index(&self) -> usize {
-        self.guess_collection.len() - 1
+    /// what is shown next to the title, e.g. "Daily #120".
+    fn mode_label(&self) -> String {
+        match self.puzzle {
+            Some(puzzle) => format!("{} #{}", self.mode, puzzle),
+            None => self.mode.to_string(),
+        }
+    }
+
+    /// the guesses and how the game is being played, for the saves.
+    fn to_record(&self) -> GameRecord {
+        GameRecord {
+            guesses: self.game.guesses(),
+            hard_mode: self.game.is_hard_mode(),
+            config: self.game.config(),
+            hints: self.hints,
+            revealed: self.revealed.clone(),
+            bot_played: self.bot_played,
+        }
+    }
+
+    /// everything needed to pick this game up again after a restart.
+    fn to_saved_game(&self) -> SavedGame {
+        SavedGame {
+            mode: self.mode,
+            puzzle: self.puzzle,
+            seed: self.seed,
+            record: self.to_record(),
+            current: self.game.current_guess().to_string(),
+        }
+    }
+
+    /// today's daily as it stands, None when this isn't a daily.
+    fn to_daily_record(&self) -> Option<DailyRecord> {
+        Some(DailyRecord {
+            puzzle: self.puzzle?,
+            record: self.to_record(),
+        })
+    }
+
+    /// keeps the saves up to date so closing the window doesn't lose the game.
+    /// a daily goes in its record too, starting the daily again picks it up from there.
+    fn save_progress(&self) -> io::Result<()> {
+        if let Some(record) = self.to_daily_record() {
+            record.save()?;
+        }
+        // a finished game has nothing left to resume.
+        if self.game.is_over() {
+            SavedGame::clear()
+        } else {
+            self.to_saved_game().save()
+        }
+    }
+
+    /// starts a game in the current mode.
+    fn new_game(&mut self, config: GameConfig, word_list: &WordList) {
+        let mut record = GameRecord {
+            config,
+            hard_mode: self.hard_mode,
+            ..Default::default()
+        };
+        if self.mode == GameMode::Daily {
+            let puzzle = puzzle_number(today());
+            self.puzzle = Some(puzzle);
+            self.seed = daily_seed(puzzle);
+            // the daily can only be played once, bring back the board as it was left,
+            // finished or not.
+            if let Some(daily) = DailyRecord::load(puzzle, config) {
+                record = daily.record;
+            }
+        } else {
+            self.puzzle = None;
+            self.seed = random_seed();
+        }
+        self.play_record(record, word_list);
+    }
+
+    /// sets up the game of the current mode and seed, then plays the record's guesses.
+    fn play_record(&mut self, record: GameRecord, word_list: &WordList) {
+        self.hints = record.hints;
+        self.revealed = record.revealed;
+        self.bot_played = record.bot_played;
+        self.absurdle = None;
+        if self.mode == GameMode::Absurdle {
+            // the same guesses always narrow the candidates down the same way.
+            self.start_absurdle(record.config, word_list, &record.guesses, record.hard_mode);
+        } else {
+            self.game = MultiGame::from_guesses(
+                record.config,
+                word_list.clone(),
+                self.seed,
+                &record.guesses,
+                record.hard_mode,
+            );
+        }
+    }
+
+    /// starts an absurdle game with every answer still possible,
+    /// then plays the guesses that were already made.
+    fn start_absurdle(
+        &mut self,
+        config: GameConfig,
+        word_list: &WordList,
+        guesses: &[String],
+        hard_mode: bool,
+    ) {
+        let absurdle = Absurdle::new(word_list);
+        self.game = absurdle.new_game(config, word_list.clone());
+        self.game.set_hard_mode(hard_mode);
+        self.absurdle = Some(absurdle);
+        for guess in guesses {
+            let submitted = guess
+                .chars()
+                .try_for_each(|letter| self.game.append(letter))
+                .and_then(|_| self.submit());
+            if submitted.is_err() {
+                // skipped, like MultiGame::from_guesses does.
+                while self.game.delete().is_ok() {}
+            }
+        }
+    }
+
+    /// submits the current guess, through absurdle when it picks the answer.
+    fn submit(&mut self) -> Result<(), GuessError> {
+        match &mut self.absurdle {
+            Some(absurdle) => absurdle.submit(&mut self.game),
+            None => self.game.submit(),
+        }
+    }
+
+    /// sets up the game a replay was recorded from, ready for its keys.
+    fn start_replay(&mut self, replay: &Replay, word_list: &WordList) {
+        self.mode = replay.mode;
+        self.puzzle = replay.puzzle;
+        self.seed = replay.seed;
+        self.play_record(replay.record.clone(), word_list);
+        // a change to how answers are picked would make the replay a different game.
+        // absurdle only settles on its answer at the end.
+        if replay.mode != GameMode::Absurdle && self.game.answers() != replay.answers {
+            warn!("the replay was recorded with other answers: {}", replay.answers.join(", "));
+        }
+        for letter in replay.current.chars() {
+            let _ = self.game.append(letter);
+        }
+    }
+
+    /// puts a saved game back on the board.
+    fn restore(&mut self, saved: SavedGame, word_list: &WordList) {
+        self.mode = saved.mode;
+        self.puzzle = saved.puzzle;
+        self.seed = saved.seed;
+        self.play_record(saved.record, word_list);
+        for letter in saved.current.chars() {
+            let _ = self.game.append(letter);
+        }
+    }
+}
+
+impl FromWorld for GameContext {
+    fn from_world(world: &mut World) -> Self {
+        let word_list = world
+            .get_resource::<WordList>()
+            .expect("expect the word list to be loaded before the game")
+            .clone();
+        let config = *world
+            .get_resource::<GameConfig>()
+            .expect("expect the game config to be loaded before the game");
+        let seed = random_seed();
+        GameContext {
+            game: MultiGame::with_seed(config, word_list, seed),
+            mode: GameMode::Daily,
+            absurdle: None,
+            puzzle: None,
+            seed,
+            hard_mode: false,
+            hints: 0,
+            revealed: Vec::new(),
+            bot_played: false,
+            resume: SavedGame::load(),
+            // put there by main when a replay file was given.
+            replay: world.remove_resource::<Replay>(),
+            replaying: false,
+            result_recorded: false,
+            game_over_pending: false,
+        }
     }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum RunState {
+pub enum RunState {
     Playing,
     GameOver
 }
 fn main() {
-    App::new()
+    let mut app = App::new();
+    // `--theme <name|file>` starts with a theme, see the ThemePlugin.
+    // `--replay <file>` watches a recorded game instead of playing, `--speed <n>` plays it faster.
+    if let Some(path) = parse_option("--replay") {
+        let replay = match Replay::load(path.as_ref()) {
+            Ok(replay) => replay,
+            Err(error) => {
+                eprintln!("couldn't load the replay {}: {}", path, error);
+                std::process::exit(2);
+            }
+        };
+        let speed = parse_option("--speed")
+            .and_then(|speed| speed.parse().ok())
+            .unwrap_or(1.0);
+        app.insert_resource(ReplayViewer::new(&replay, speed))
+            .insert_resource(replay);
+    }
+    app
         .add_plugins(DefaultPlugins)
         .add_plugin(GameUiPlugin)
+        .add_plugin(AnimationPlugin)
+        .add_plugin(StatsUiPlugin)
+        .add_plugin(HintUiPlugin)
+        .add_plugin(BotUiPlugin)
+        .add_plugin(ReplayPlugin)
+        .add_plugin(ThemePlugin)
+        .add_plugin(ColorblindPlugin)
         .init_resource::<FontSpec>()
+        .init_resource::<GameConfig>()
+        .init_resource::<WordList>()
         .init_resource::<GameContext>()
+        .insert_resource(Stats::load(GameConfig::default()))
         .add_startup_system(setup)
-        .add_startup_system(spawn_board)
         .add_event::<GuessUpdateEvent>()
         .add_state(RunState::Playing)
         .add_system_set(
             SystemSet::on_update(RunState::Playing)
                 .with_system(guess_update_handler)
+                .with_system(finish_game)
         )
         .add_system_set(
             SystemSet::on_enter(RunState::Playing)
-                .with_system(game_reset)
-                .with_system(spawn_tiles)
+                .with_system(game_reset.label("game_reset"))
+                .with_system(spawn_board.after("game_reset"))
+                .with_system(spawn_tiles.after("game_reset"))
+        )
+        .add_system_set(
+            SystemSet::on_enter(RunState::GameOver)
+                .with_system(game_over.label("game_over"))
         )
         .run()
 }
@@ -212,27 +551,50 @@ fn setup(mut commands: Commands) {
         .spawn_bundle(OrthographicCameraBundle::new_2d());
 }
 
-/// spawn a wordle game board.
-fn spawn_board(mut commands: Commands) {
-    // 5 columns (the word to guess is a 5 letter word)
-    // 6 rows (user gets 6 guesses)
-    let board = Board::new(5, 6);
+/// spawn the wordle game boards, one for each board in the game.
+/// the boards are sized by the GameConfig, so they are replaced when the config changes.
+fn spawn_board(
+    mut commands: Commands,
+    boards: Query<(Entity, &Board)>,
+    config: Res<GameConfig>,
+    theme: Res<Theme>,
+) {
+    // one column per letter in the word, one row per guess.
+    let layout = Board::layout(&config);
+    let up_to_date = boards.iter().count() == layout.len()
+        && boards.iter().all(|(_, old_board)| {
+            old_board.columns == layout[0].columns && old_board.rows == layout[0].rows
+        });
+    if up_to_date {
+        return;
+    }
+    for (entity, _) in boards.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for board in layout {
+        spawn_single_board(&mut commands, board, &theme);
+    }
+}
+
+fn spawn_single_board(commands: &mut Commands, board: Board, theme: &Theme) {
     // spawn wordle board
     commands
         // board background
         .spawn_bundle(SpriteBundle {
             sprite: Sprite {
-                color: MATERIALS.board,
+                color: theme.board,
                 custom_size: Some(Vec2::new(
                     board.width,
                     board.height,
                 )),
                 ..Sprite::default()
             },
-            // move the board up 100 units
-            transform: Transform::from_xyz(0.0, 100.0, 1.0),
+            // the board's center is moved up 100 units to make room for the keyboard.
+            transform: Transform::from_xyz(board.center.x, board.center.y, 1.0)
+                .with_scale(Vec3::new(board.scale, board.scale, 1.0)),
             ..Default::default()
     })
+    .insert(ThemeColor::Board)
     .with_children(|builder| {
         // tile placeholders
         for tile in (0..board.columns)
@@ -240,7 +602,7 @@ fn spawn_board(mut commands: Commands) {
                 // spawn tile placeholder.
                 builder.spawn_bundle(SpriteBundle {
                     sprite: Sprite {
-                        color: MATERIALS.tile_placeholder,
+                        color: theme.tile_placeholder,
                         custom_size: Some(Vec2::new(
                             TILE_PLACEHOLDER_SIZE, TILE_PLACEHOLDER_SIZE,
                         )),
@@ -256,7 +618,8 @@ fn spawn_board(mut commands: Commands) {
                         1.0,
                     ),
                     ..Default::default()
-                });
+                })
+                .insert(ThemeColor::TilePlaceholder);
         }
     })
     .insert(board);
@@ -266,39 +629,38 @@ fn spawn_tile(
     commands: &mut Commands,
     board: &Board,
     font_spec: &Res<FontSpec>,
+    theme: &Theme,
     pos: Position,
+    letter: &str,
+    // Tile until the tile is scored.
+    role: ThemeColor,
 ) {
+    let translation = board.tile_translation(pos);
     commands
         .spawn_bundle(SpriteBundle {
             sprite: Sprite {
-                color: MATERIALS.tile,
+                color: theme.color(role),
                 custom_size: Some(Vec2::new(
                     TILE_SIZE, TILE_SIZE,
                 )),
                 ..Sprite::default()
             },
-            transform: Transform::from_xyz(
-                board.column_position_to_physical(
-                    pos.x
-                ),
-                board.row_position_to_physical(
-                    pos.y
-                ) + 100.0, // matching the board's offset that makes room for keyboard
-                3.0,
-            ),
+            // tiles aren't children of the board, so they are scaled along with it here.
+            transform: Transform::from_xyz(translation.x, translation.y, 3.0)
+                .with_scale(Vec3::new(board.scale, board.scale, 1.0)),
             ..Default::default()
         })
         .with_children(|child_builder| {
             child_builder
                 .spawn_bundle(Text2dBundle {
                     text: Text::with_section(
-                        "",
+                        letter,
                         TextStyle {
                             font: font_spec
                                 .family
                                 .clone(),
                             font_size: 40.0,
-                            color: Color::BLACK,
+                            color: theme.tile_letter,
                             ..Default::default()
                         },
                         TextAlignment {
@@ -313,105 +675,194 @@ fn spawn_tile(
                     ),
                     ..Default::default()
                 })
-                .insert(TileText);
+                .insert(TileText)
+                .insert(ThemeColor::TileLetter);
+            // shown by the tile_marker_system once the tile is scored.
+            child_builder
+                .spawn_bundle(SpriteBundle {
+                    sprite: Sprite {
+                        color: theme.tile_letter,
+                        ..Sprite::default()
+                    },
+                    transform: Transform::from_xyz(0.0, 0.0, 0.5),
+                    visibility: Visibility { is_visible: false },
+                    ..Default::default()
+                })
+                .insert(TileMarker)
+                .insert(ThemeColor::TileLetter);
         })
-        .insert(Position{x: pos.x, y: pos.y});
+        .insert(role)
+        .insert(pos)
+        .insert(TileAnimation::default());
 }
 
 fn spawn_tiles(
     mut commands: Commands,
-    query_board: Query<&Board>,
+    config: Res<GameConfig>,
     font_spec: Res<FontSpec>,
+    theme: Res<Theme>,
+    game_context: Res<GameContext>,
 ) {
-    let board = query_board.single();
-    for (x, y) in (0..board.columns)
-        .cartesian_product(0..board.rows) {
-        spawn_tile(&mut commands, board, &font_spec, Position{x,y});
+    // the board entities may have been spawned this frame and aren't in a query yet,
+    // where they go only depends on the config anyway.
+    let game = &game_context.game;
+    for board in Board::layout(&config) {
+        let board_game = &game.boards()[board.index];
+        for (x, y) in (0..board.columns)
+            .cartesian_product(0..board.rows) {
+            // a game that was already played (e.g. today's daily) is drawn as it was left.
+            let guess_index = (board.rows - 1 - y) as usize;
+            let (letter, role) = match board_game.rows().get(guess_index) {
+                Some(scored) => (
+                    scored.word.chars().nth(x as usize),
+                    ThemeColor::for_score(scored.scores[x as usize]),
+                ),
+                // a solved board doesn't show the guess being typed.
+                None if guess_index == game.guess_index() && !board_game.is_over() => (
+                    game.current_guess().chars().nth(x as usize),
+                    ThemeColor::Tile,
+                ),
+                None => (None, ThemeColor::Tile),
+            };
+            let letter = letter.map(String::from).unwrap_or_default();
+            let pos = Position{board: board.index, x, y};
+            spawn_tile(&mut commands, &board, &font_spec, &theme, pos, &letter, role);
+        }
     }
 }
 fn guess_update_handler(
     mut guess_reader: EventReader<GuessUpdateEvent>,
-    mut commands: Commands,
     // p0 - tile texts from board.
     // p1 - MessageText for displaying messages to user
     mut text_set: ParamSet<(Query<&mut Text, With<TileText>>,
                             Query<&mut Text, With<MessageText>>)>,
     mut tiles: Query<
-        (&Position, &Children),
+        (&Position, &Children, &mut Sprite, &mut TileAnimation, &mut ThemeColor),
         >,
-    font_spec: Res<FontSpec>,
     mut game_context: ResMut<GameContext>,
+    reduced_motion: Res<ReducedMotion>,
+    theme: Res<Theme>,
+    mut run_state: ResMut<State<RunState>>,
 ) {
-    let guess_index = game_context.get_guess_index();
-    // row 0 of the board is at the bottom..
-    // I want the guesses to display from top to bottom not bottom to top.
-    // reversing the display starting position is needed.
-    // LAST_GUESS_INDEX - index of guess will give me the correct Y position.
-    // EX: last guess index (5) - guess index (0) = 5.
-    // guess_index increments to 1 after user submits guess then 5 - 1 = 4.
-    let guess_display_index = LAST_GUESS_INDEX as usize - guess_index;
-    let guess: &mut String = &mut game_context.guess_collection[guess_index];
+    // true once any event changed the game and it needs saving.
+    let mut accepted = false;
     // update the guess..
     for event in guess_reader.iter() {
+        let game = &game_context.game;
+        // row 0 of the board is at the bottom..
+        // I want the guesses to display from top to bottom not bottom to top.
+        // reversing the display starting position is needed.
+        // last guess index - index of guess will give me the correct Y position.
+        // EX: with 6 guesses, last guess index (5) - guess index (0) = 5.
+        // guess_index increments to 1 after user submits guess then 5 - 1 = 4.
+        let guess_index = game.guess_index();
+        let guess_display_index = game.config().max_guesses - 1 - guess_index;
         // update guess or submit
-        match event.action {
-            GuessUpdateAction::Delete => {guess.pop();}
-            GuessUpdateAction::Append => {guess.push_str(event.key.as_str());},
-            GuessUpdateAction::Submit => {
-                let mut message_display_text = text_set.p1();
-                if guess.len() < 5 {
-                    let mut msg_text = message_display_text
-                                    .get_single_mut()
-                                    .expect("expect message text to exist.");
-                    let mut msg_section = msg_text.sections
-                                        .first_mut()
-                                        .expect("expect first text section to be accessible as mutable");
-                    msg_section.value = "5 characters required to submit guess.".to_string();
+        let result = match event.action {
+            GuessUpdateAction::Delete => game_context.game.delete(),
+            GuessUpdateAction::Append => event.key.chars().try_for_each(|c| game_context.game.append(c)),
+            // goes through the context so absurdle can pick its answer first.
+            GuessUpdateAction::Submit => game_context.submit(),
+        };
+        let game = &game_context.game;
+        if let Err(error) = result {
+            // the guess is left as it was, e.g. the row stays editable
+            // when the word isn't in the word list.
+            show_message(&mut text_set.p1(), &error.to_string());
+            if let (GuessUpdateAction::Submit, false) = (&event.action, reduced_motion.0) {
+                for (position, _children, _sprite, mut animation, _role) in tiles.iter_mut() {
+                    if position.y as usize == guess_display_index && !game.boards()[position.board].is_over() {
+                        *animation = TileAnimation::shake();
+                    }
                 }
-            },
+            }
+            continue;
         }
+        accepted = true;
         //
         match event.action {
             GuessUpdateAction::Delete |
             GuessUpdateAction::Append => {
+                let guess = game.current_guess();
                 // update board now with this guess information.
-                let mut it = tiles
+                let row_tiles = tiles
                     .iter_mut()
-                    .filter(|(pos, _children)|{
+                    .filter(|(pos, _children, _sprite, _animation, _role)|{
                         // only want tiles that are in the same rows as the
-                        // guess we are working with
+                        // guess we are working with, on boards still being played.
                         pos.y as usize == guess_display_index
-                    })
-                    .sorted_by(|a, b|{
-                        // order by column
-                        match Ord::cmp(&a.0.x, &b.0.x) {
-                            a => a
-                        }
+                            && !game.boards()[pos.board].is_over()
                     });
-                let mut guess_chars = guess.chars();
-                // while there are still tiles to process
-                while let Some((position, children)) = it.next() {
-                    if let Some(entity) = children.first() {
-                        if position.x as usize > guess.len() {
-                            break
-                        }
+                for (position, children, _sprite, mut animation, _role) in row_tiles {
+                    if let Some(text_entity) = children.first() {
                         let mut tile_texts = text_set.p0();
                         let mut text = tile_texts
-                            .get_mut(*entity)
+                            .get_mut(*text_entity)
                             .expect("expected Text to exist");
-                        let mut text_section = text.sections.first_mut()
+                        let text_section = text.sections.first_mut()
                             .expect("expect first section to be accessible as mutable");
-                        // get the next character of the guess
-                        match guess_chars.next() {
+                        // the letter of the guess in this column
+                        match guess.chars().nth(position.x as usize) {
                             // got a character. put that in the tile
-                            Some(c) => text_section.value = c.to_string(),
+                            Some(c) => {
+                                // only the letter that was just typed pops.
+                                let typed = text_section.value.is_empty();
+                                text_section.value = c.to_string();
+                                if typed && !reduced_motion.0 {
+                                    *animation = TileAnimation::pop();
+                                }
+                            }
                             // no character there clear out the tile.
                             None => text_section.value = "".to_string(),
                         }
                     }
                 }
             }
-            _ => ()
+            GuessUpdateAction::Submit => {
+                // paint each tile in the row with its result.
+                // boards that were already solved didn't get this guess and stay as they are.
+                // the tiles flip over one by one unless motion is turned down.
+                for (position, _children, mut sprite, mut animation, mut role) in tiles
+                    .iter_mut()
+                    .filter(|(pos, _children, _sprite, _animation, _role)| pos.y as usize == guess_display_index) {
+                    if let Some(scored) = game.boards()[position.board].rows().get(guess_index) {
+                        let score_role = ThemeColor::for_score(scored.scores[position.x as usize]);
+                        if reduced_motion.0 {
+                            *role = score_role;
+                            sprite.color = theme.color(score_role);
+                        } else {
+                            let columns = scored.scores.len() as u8;
+                            let solved = is_solved(&scored.scores);
+                            *animation = TileAnimation::reveal(score_role, position.x, columns, solved);
+                        }
+                    }
+                }
+                if let (Some(absurdle), false) = (&game_context.absurdle, game.is_over()) {
+                    let left = absurdle.candidates().len();
+                    show_message(&mut text_set.p1(), &format!(
+                        "{} {} left",
+                        left,
+                        if left == 1 { "word" } else { "words" },
+                    ));
+                }
+                if game.is_over() {
+                    // the answer and the stats wait for the row to finish flipping.
+                    if reduced_motion.0 {
+                        run_state
+                            .set(RunState::GameOver)
+                            .expect("expect to be able to end the game");
+                    } else {
+                        game_context.game_over_pending = true;
+                    }
+                    // the game is over, anything left in the queue is ignored.
+                    break;
+                }
+            }
+        }
+    }
+    if accepted && !game_context.replaying {
+        if let Err(error) = game_context.save_progress() {
+            warn!("couldn't save the game: {}", error);
         }
     }
 }
@@ -420,10 +871,144 @@ fn guess_update_handler(
 fn game_reset(
     mut commands: Commands,
     tiles: Query<Entity, With<Position>>,
-    mut game: ResMut<GameContext>,
+    mut game_context: ResMut<GameContext>,
+    mut config: ResMut<GameConfig>,
+    mut word_list: ResMut<WordList>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+    mut run_state: ResMut<State<RunState>>,
 ) {
     for entity in tiles.iter() {
         commands.entity(entity).despawn_recursive();
     }
-    game.guess_collection = vec!["".to_string()];
-}
\ No newline at end of file
+    show_message(&mut message_query, "");
+    // a replay from the command line is watched instead of the first game.
+    let replay = game_context.replay.take()
+        .filter(|replay| WordList::for_length(replay.record.config.word_length).is_some());
+    game_context.replaying = replay.is_some();
+    // an unfinished game from last time is only picked up at startup.
+    // yesterday's daily can't be finished today.
+    let resume = game_context.resume.take().filter(|saved| {
+        saved.mode != GameMode::Daily || saved.puzzle == Some(puzzle_number(today()))
+    })
+    .filter(|saved| WordList::for_length(saved.record.config.word_length).is_some())
+    .filter(|_| replay.is_none());
+    // the saved game is played with the config it was started with.
+    if let Some(replay) = &replay {
+        *config = replay.record.config;
+    } else if let Some(saved) = &resume {
+        *config = saved.record.config;
+    } else if game_context.mode == GameMode::Absurdle && config.boards != 1 {
+        // absurdle is played on a single board.
+        *config = GameConfig::for_word_length(config.word_length)
+            .expect("expect a config for the word length being played");
+    }
+    if word_list.word_length() != config.word_length {
+        *word_list = WordList::for_length(config.word_length)
+            .expect("expect a word list for the configured word length");
+    }
+    match (replay, resume) {
+        (Some(replay), _) => game_context.start_replay(&replay, &word_list),
+        (None, Some(saved)) => game_context.restore(saved, &word_list),
+        (None, None) => game_context.new_game(*config, &word_list),
+    }
+    // a replay isn't a game the player finished, it stays out of the stats.
+    game_context.result_recorded = game_context.game.is_over() || game_context.replaying;
+    game_context.game_over_pending = false;
+    if game_context.game.is_over() {
+        run_state.overwrite_set(RunState::GameOver)
+            .expect("expect to be able to show the finished game");
+    }
+}
+
+/// ends a game that is over once its last row has flipped over,
+/// and bounced when it found the answer.
+fn finish_game(
+    mut game_context: ResMut<GameContext>,
+    revealing: Res<Revealing>,
+    animations: Query<&TileAnimation>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    if !game_context.game_over_pending
+        || revealing.0
+        || animations.iter().any(|animation| !animation.is_finished()) {
+        return;
+    }
+    game_context.game_over_pending = false;
+    run_state
+        .set(RunState::GameOver)
+        .expect("expect to be able to end the game");
+}
+
+/// tells the player how the game ended.
+/// the answers are revealed if they didn't get them.
+/// the game goes in the stats for its config, see load_stats.
+/// absurdle games are left out, they are a lot harder than a normal game.
+/// so are games the bot played, they aren't the player's.
+fn game_over(
+    mut game_context: ResMut<GameContext>,
+    mut stats: ResMut<Stats>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    if !game_context.result_recorded {
+        game_context.result_recorded = true;
+        let left_out = game_context.mode == GameMode::Absurdle || game_context.bot_played;
+        if !left_out {
+            stats.record(&game_context.game);
+            if let (Some(puzzle), true) = (game_context.puzzle, game_context.hints > 0) {
+                stats.mark_hinted_daily(puzzle);
+            }
+            if let Err(error) = stats.save() {
+                warn!("couldn't save stats: {}", error);
+            }
+        }
+    }
+    let game = &game_context.game;
+    let next = match game_context.puzzle {
+        // a replay of an old daily mustn't replace today's result.
+        Some(_) if game_context.replaying => "That was a replay.",
+        Some(_) => "Come back tomorrow for a new word.",
+        None => "Press ENTER to play again.",
+    };
+    let hints = match game_context.hints {
+        0 => String::new(),
+        1 => "Used 1 hint. ".to_string(),
+        hints => format!("Used {} hints. ", hints),
+    };
+    let bot = if game_context.bot_played { "Played by the bot. " } else { "" };
+    let next = format!("{}{}{}", bot, hints, next);
+    let boards = match game.boards().len() {
+        1 => String::new(),
+        boards => format!(" all {} boards", boards),
+    };
+    let still_possible = game_context.absurdle
+        .as_ref()
+        .map_or(1, |absurdle| absurdle.candidates().len());
+    let message = match (game.status(), game.answers().as_slice()) {
+        (GameStatus::Won, _) => format!(
+            "Solved{} in {}/{}{}! {}",
+            boards,
+            game.guess_index(),
+            game.config().max_guesses,
+            if game.is_hard_mode() { " in hard mode" } else { "" },
+            next,
+        ),
+        // absurdle never settled on a word, any that are left would have done.
+        (_, [answer]) if still_possible > 1 => format!(
+            "{} words were still possible, like {}. {}",
+            still_possible,
+            answer,
+            next,
+        ),
+        (_, [answer]) => format!(
+            "The word was {}. {}",
+            answer,
+            next,
+        ),
+        (_, answers) => format!(
+            "The words were {}. {}",
+            answers.join(", "),
+            next,
+        ),
+    };
+    show_message(&mut message_query, &message);
+}
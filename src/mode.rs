@@ -0,0 +1,45 @@
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// the day puzzle #0 was played. Every day after gets the next number.
+const FIRST_PUZZLE: (i32, u32, u32) = (2022, 1, 1);
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Serialize, Deserialize)]
+pub enum GameMode {
+    // one word per calendar day, the same for everyone.
+    Daily,
+    // a new random word every game.
+    Practice,
+    // no answer until the guesses leave no choice.
+    Absurdle,
+}
+
+impl std::fmt::Display for GameMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameMode::Daily => write!(f, "Daily"),
+            GameMode::Practice => write!(f, "Practice"),
+            GameMode::Absurdle => write!(f, "Absurdle"),
+        }
+    }
+}
+
+/// today's date on this machine.
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// number of the daily puzzle for a date.
+/// days before the first puzzle get puzzle #0.
+pub fn puzzle_number(date: NaiveDate) -> u32 {
+    let (year, month, day) = FIRST_PUZZLE;
+    let first = NaiveDate::from_ymd_opt(year, month, day)
+        .expect("expect the first puzzle date to be a real date");
+    date.signed_duration_since(first).num_days().max(0) as u32
+}
+
+/// seed the daily answer is picked with.
+/// everyone gets the same answer for the same puzzle.
+pub fn daily_seed(puzzle: u32) -> u64 {
+    u64::from(puzzle)
+}
@@ -0,0 +1,268 @@
+use crate::config::GameConfig;
+use crate::game::{Game, GameStatus, GuessError};
+use crate::words::{splitmix64, WordList};
+
+/// Every guess played on several boards at once, each with its own answer
+/// (Dordle, Quordle, ...). With one board it plays exactly like a Game.
+///
+/// A board that is solved stops taking guesses, the game is won once every board is solved.
+#[derive(Debug, Clone)]
+pub struct MultiGame {
+    config: GameConfig,
+    boards: Vec<Game>,
+    // every guess that was accepted, solved boards only have the ones up to their answer.
+    guesses: Vec<String>,
+    // guess being typed in, shared by every board.
+    current: String,
+}
+
+impl MultiGame {
+    /// one board per answer. The answers should be upper case, like the word list.
+    pub fn new(config: GameConfig, words: WordList, answers: &[String]) -> Self {
+        let boards = answers
+            .iter()
+            .map(|answer| Game::new(config, words.clone(), answer))
+            .collect();
+        MultiGame {
+            config,
+            boards,
+            guesses: Vec::new(),
+            current: String::new(),
+        }
+    }
+
+    /// starts a game with config.boards answers picked by seed, see pick_answers.
+    pub fn with_seed(config: GameConfig, words: WordList, seed: u64) -> Self {
+        let answers = pick_answers(&words, seed, config.boards);
+        MultiGame::new(config, words, &answers)
+    }
+
+    /// rebuilds a game from guesses that were already made.
+    /// guesses that can't be submitted anymore are skipped.
+    pub fn from_guesses(
+        config: GameConfig,
+        words: WordList,
+        seed: u64,
+        guesses: &[String],
+        hard_mode: bool,
+    ) -> Self {
+        let mut game = MultiGame::with_seed(config, words, seed);
+        game.set_hard_mode(hard_mode);
+        for guess in guesses {
+            game.current.clear();
+            if guess.chars().try_for_each(|letter| game.append(letter)).is_ok() {
+                let _ = game.submit();
+            }
+        }
+        game.current.clear();
+        game
+    }
+
+    pub fn config(&self) -> GameConfig {
+        self.config
+    }
+
+    pub fn boards(&self) -> &[Game] {
+        &self.boards
+    }
+
+    /// the answer of every board, in board order.
+    pub fn answers(&self) -> Vec<&str> {
+        self.boards.iter().map(Game::answer).collect()
+    }
+
+    /// swaps the answer of one board, see Game::replace_answer.
+    pub fn replace_answer(&mut self, board: usize, answer: &str) {
+        self.boards[board].replace_answer(answer);
+    }
+
+    /// the words of every accepted guess.
+    pub fn guesses(&self) -> Vec<String> {
+        self.guesses.clone()
+    }
+
+    pub fn current_guess(&self) -> &str {
+        &self.current
+    }
+
+    /// row the current guess is typed into. 0 is the first guess.
+    pub fn guess_index(&self) -> usize {
+        self.guesses.len()
+    }
+
+    /// won once every board is solved, lost once a board runs out of guesses.
+    pub fn status(&self) -> GameStatus {
+        if self.boards.iter().all(|board| board.status() == GameStatus::Won) {
+            GameStatus::Won
+        } else if self.boards.iter().any(|board| board.status() == GameStatus::Lost) {
+            GameStatus::Lost
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.status() != GameStatus::InProgress
+    }
+
+    pub fn is_hard_mode(&self) -> bool {
+        self.boards.iter().any(Game::is_hard_mode)
+    }
+
+    /// turns hard mode on or off for every board.
+    /// only allowed before the first guess, returns false if it was too late.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) -> bool {
+        if !self.guesses.is_empty() {
+            return self.is_hard_mode() == hard_mode;
+        }
+        self.boards
+            .iter_mut()
+            .all(|board| board.set_hard_mode(hard_mode))
+    }
+
+    /// adds a letter to the end of the current guess.
+    pub fn append(&mut self, letter: char) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        if !letter.is_ascii_alphabetic() {
+            return Err(GuessError::NotALetter);
+        }
+        if self.current.len() >= self.config.word_length {
+            return Err(GuessError::GuessFull);
+        }
+        self.current.push(letter.to_ascii_uppercase());
+        Ok(())
+    }
+
+    /// removes the last letter of the current guess.
+    pub fn delete(&mut self) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        self.current.pop().map(|_| ()).ok_or(GuessError::GuessEmpty)
+    }
+
+    /// plays the current guess on every board that isn't solved yet.
+    /// every board has to accept the guess before any of them is scored,
+    /// so the boards never get out of step.
+    pub fn submit(&mut self) -> Result<(), GuessError> {
+        self.check_guess()?;
+        let word = std::mem::take(&mut self.current);
+        for board in self.boards.iter_mut().filter(|board| !board.is_over()) {
+            for letter in word.chars() {
+                board.append(letter)?;
+            }
+            board.submit()?;
+        }
+        self.guesses.push(word);
+        Ok(())
+    }
+
+    /// the reason the current guess would be turned down by submit, without submitting it.
+    pub fn check_guess(&self) -> Result<(), GuessError> {
+        self.check_in_progress()?;
+        for board in self.boards.iter().filter(|board| !board.is_over()) {
+            board.check_guess(&self.current)?;
+        }
+        Ok(())
+    }
+
+    fn check_in_progress(&self) -> Result<(), GuessError> {
+        match self.status() {
+            GameStatus::InProgress => Ok(()),
+            _ => Err(GuessError::GameOver),
+        }
+    }
+}
+
+/// picks a different answer for every board.
+/// one board gets the answer a Game with the seed would have. with more boards none of them
+/// gets that one, it would give the one board daily away.
+/// each board picks from its own stream of seeds, mixed with the number of boards.
+/// seed + 1 would be the next daily puzzle and give tomorrow's answer away.
+fn pick_answers(words: &WordList, seed: u64, count: usize) -> Vec<String> {
+    if count == 1 {
+        return vec![words.pick_answer(seed).to_string()];
+    }
+    let single = words.pick_answer(seed);
+    let board_seed = |board: usize| splitmix64(seed ^ ((count as u64) << 32 | board as u64));
+    let mut answers: Vec<String> = Vec::with_capacity(count);
+    let mut next_seed = board_seed(0);
+    while answers.len() < count.min(words.answers().len().saturating_sub(1)) {
+        let answer = words.pick_answer(next_seed);
+        if answer != single && !answers.iter().any(|picked| picked == answer) {
+            answers.push(answer.to_string());
+            next_seed = board_seed(answers.len());
+        } else {
+            // taken already, try the next seed in this board's stream.
+            next_seed = splitmix64(next_seed);
+        }
+    }
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_boards(answers: &[&str]) -> MultiGame {
+        let config = GameConfig::new(5, 2).expect("expect a config for two boards");
+        let answers: Vec<String> = answers.iter().map(|answer| answer.to_string()).collect();
+        MultiGame::new(config, WordList::default(), &answers)
+    }
+
+    fn play(game: &mut MultiGame, guess: &str) -> Result<(), GuessError> {
+        guess.chars().try_for_each(|letter| game.append(letter))?;
+        game.submit()
+    }
+
+    #[test]
+    fn every_board_gets_its_own_answer() {
+        let words = WordList::default();
+        for seed in 0..50 {
+            let single = words.pick_answer(seed);
+            assert_eq!(pick_answers(&words, seed, 1), vec![single.to_string()]);
+            for count in [2, 4, 8] {
+                let answers = pick_answers(&words, seed, count);
+                assert_eq!(answers.len(), count);
+                assert!(!answers.iter().any(|answer| answer == single), "seed {} has the one board answer", seed);
+                for (board, answer) in answers.iter().enumerate() {
+                    assert!(!answers[..board].contains(answer), "seed {} has {} twice", seed, answer);
+                }
+                // the same seed gives the same game.
+                assert_eq!(answers, pick_answers(&words, seed, count));
+            }
+        }
+    }
+
+    #[test]
+    fn a_solved_board_takes_no_more_guesses() {
+        let mut game = two_boards(&["ABIDE", "CRANE"]);
+        play(&mut game, "ABIDE").unwrap();
+        assert_eq!(game.boards()[0].status(), GameStatus::Won);
+        assert_eq!(game.status(), GameStatus::InProgress);
+        play(&mut game, "SPEED").unwrap();
+        assert_eq!(game.boards()[0].rows().len(), 1);
+        assert_eq!(game.boards()[1].rows().len(), 2);
+        assert_eq!(game.guesses(), vec!["ABIDE".to_string(), "SPEED".to_string()]);
+    }
+
+    #[test]
+    fn won_once_every_board_is_solved() {
+        let mut game = two_boards(&["ABIDE", "CRANE"]);
+        play(&mut game, "CRANE").unwrap();
+        play(&mut game, "ABIDE").unwrap();
+        assert_eq!(game.status(), GameStatus::Won);
+        assert_eq!(play(&mut game, "SPEED"), Err(GuessError::GameOver));
+    }
+
+    #[test]
+    fn lost_once_a_board_runs_out_of_guesses() {
+        let mut game = two_boards(&["ABIDE", "CRANE"]);
+        play(&mut game, "ABIDE").unwrap();
+        for guess in ["SPEED", "THEME", "AUDIO", "CIDER", "ASIDE"] {
+            play(&mut game, guess).unwrap();
+            assert_eq!(game.status(), GameStatus::InProgress);
+        }
+        // two boards get 7 guesses.
+        play(&mut game, "ABODE").unwrap();
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+}
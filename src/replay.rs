@@ -0,0 +1,77 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::mode::GameMode;
+use crate::storage::{data_dir, GameRecord};
+
+/// version written to new replay files.
+/// bump it when a change means older builds can't play the file back.
+pub const REPLAY_VERSION: u32 = 1;
+
+// folder in the data directory replays are saved to.
+const REPLAY_DIR: &str = "replays";
+
+/// Every key that reached the game, with when it happened,
+/// and what's needed to set the same game up again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub mode: GameMode,
+    // daily puzzle number, None in practice mode.
+    pub puzzle: Option<u32>,
+    // the answers are picked again from the seed.
+    pub seed: u64,
+    // the game when recording started, its guesses were made before that,
+    // e.g. a game picked up from last time.
+    #[serde(flatten)]
+    pub record: GameRecord,
+    // answer of every board at the end, to check the replay plays out the same.
+    pub answers: Vec<String>,
+    // the guess that was being typed in when recording started.
+    pub current: String,
+    pub events: Vec<ReplayEvent>,
+}
+
+/// a key that was sent to the game, BACK_KEY and ENTER_KEY included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    // since recording started.
+    pub seconds: f64,
+    pub key: String,
+}
+
+impl Replay {
+    /// reads a replay file.
+    /// files from a newer version of the game are turned down instead of played wrong.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let replay: Replay = serde_json::from_str(&text)?;
+        if replay.version > REPLAY_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "replay version {} is newer than this game can play (version {})",
+                    replay.version, REPLAY_VERSION,
+                ),
+            ));
+        }
+        Ok(replay)
+    }
+
+    /// writes the replay to the replays folder in the data directory,
+    /// named after when it was saved. returns where it went.
+    pub fn save(&self) -> io::Result<PathBuf> {
+        let dir = data_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory on this system"))?
+            .join(REPLAY_DIR);
+        fs::create_dir_all(&dir)?;
+        let mode = self.mode.to_string().to_lowercase();
+        let path = dir.join(format!("{}-{}.json", Local::now().format("%Y%m%d-%H%M%S"), mode));
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+use wordle::replay::{Replay, ReplayEvent, REPLAY_VERSION};
+
+use crate::ui::{show_message, MessageText};
+use crate::{GameContext, GuessUpdateAction, GuessUpdateEvent, RunState};
+
+// replay speeds the viewer can be switched between.
+const MIN_SPEED: f64 = 0.25;
+const MAX_SPEED: f64 = 16.0;
+
+pub struct ReplayPlugin;
+
+/// The game being played, saved as a replay once it's over.
+#[derive(Default)]
+struct Recorder {
+    replay: Option<Replay>,
+    // seconds since startup when the game started.
+    started: f64,
+}
+
+/// Plays a replay file back, set up from the command line.
+/// Space pauses, Right plays the next key, Up and Down change the speed.
+pub struct ReplayViewer {
+    events: Vec<ReplayEvent>,
+    // index of the next event to send.
+    next: usize,
+    // seconds into the replay, sped up by speed.
+    clock: f64,
+    speed: f64,
+    paused: bool,
+}
+
+impl ReplayViewer {
+    pub fn new(replay: &Replay, speed: f64) -> Self {
+        ReplayViewer {
+            events: replay.events.clone(),
+            next: 0,
+            clock: 0.0,
+            speed: speed.clamp(MIN_SPEED, MAX_SPEED),
+            paused: false,
+        }
+    }
+}
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Recorder>()
+            .add_system(record_events)
+            .add_system(replay_viewer_system)
+            .add_system_set(
+                SystemSet::on_enter(RunState::Playing)
+                    .with_system(start_recording.after("game_reset"))
+            )
+            .add_system_set(
+                SystemSet::on_enter(RunState::GameOver)
+                    .with_system(save_recording.after("game_over"))
+            );
+    }
+}
+
+/// starts recording the game that was just set up.
+/// a replay being watched isn't recorded again.
+fn start_recording(
+    time: Res<Time>,
+    game_context: Res<GameContext>,
+    mut recorder: ResMut<Recorder>,
+) {
+    if game_context.replaying {
+        recorder.replay = None;
+        return;
+    }
+    let game = &game_context.game;
+    recorder.started = time.seconds_since_startup();
+    recorder.replay = Some(Replay {
+        version: REPLAY_VERSION,
+        mode: game_context.mode,
+        puzzle: game_context.puzzle,
+        seed: game_context.seed,
+        record: game_context.to_record(),
+        answers: Vec::new(),
+        current: game.current_guess().to_string(),
+        events: Vec::new(),
+    });
+}
+
+/// keeps every key sent to the game, including the ones it turned down.
+fn record_events(
+    time: Res<Time>,
+    mut guess_reader: EventReader<GuessUpdateEvent>,
+    mut recorder: ResMut<Recorder>,
+) {
+    let seconds = time.seconds_since_startup() - recorder.started;
+    if let Some(replay) = &mut recorder.replay {
+        for event in guess_reader.iter() {
+            replay.events.push(ReplayEvent {
+                seconds,
+                key: event.key.clone(),
+            });
+        }
+    }
+}
+
+/// saves the replay of the game that just ended.
+/// a game that was over before it started (e.g. today's daily) has nothing to save.
+fn save_recording(
+    game_context: Res<GameContext>,
+    mut recorder: ResMut<Recorder>,
+) {
+    let mut replay = match recorder.replay.take() {
+        Some(replay) if !replay.events.is_empty() => replay,
+        _ => return,
+    };
+    // hard mode can be switched until the first guess, it's settled by now.
+    replay.record.hard_mode = game_context.game.is_hard_mode();
+    // absurdle only settles on its answer at the end.
+    replay.answers = game_context
+        .game
+        .answers()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    match replay.save() {
+        Ok(path) => info!("saved the replay to {}", path.display()),
+        Err(error) => warn!("couldn't save the replay: {}", error),
+    }
+}
+
+/// sends the recorded keys to the game when their time comes,
+/// and handles the pause, step and speed keys.
+fn replay_viewer_system(
+    time: Res<Time>,
+    keys: Res<Input<KeyCode>>,
+    viewer: Option<ResMut<ReplayViewer>>,
+    game_context: Res<GameContext>,
+    mut guess_writer: EventWriter<GuessUpdateEvent>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    let mut viewer = match viewer {
+        // stops once another game is started.
+        Some(viewer) if game_context.replaying => viewer,
+        _ => return,
+    };
+    if keys.just_pressed(KeyCode::Space) {
+        viewer.paused = !viewer.paused;
+        let message = if viewer.paused { "Replay paused".to_string() } else { speed_message(viewer.speed) };
+        show_message(&mut message_query, &message);
+    }
+    if keys.just_pressed(KeyCode::Up) {
+        viewer.speed = (viewer.speed * 2.0).min(MAX_SPEED);
+        show_message(&mut message_query, &speed_message(viewer.speed));
+    }
+    if keys.just_pressed(KeyCode::Down) {
+        viewer.speed = (viewer.speed / 2.0).max(MIN_SPEED);
+        show_message(&mut message_query, &speed_message(viewer.speed));
+    }
+    if keys.just_pressed(KeyCode::Right) {
+        // jumps the clock to the next key, the rest waits as long as it did.
+        if let Some(event) = viewer.events.get(viewer.next) {
+            viewer.clock = event.seconds;
+        }
+    } else if !viewer.paused {
+        viewer.clock += time.delta_seconds_f64() * viewer.speed;
+    }
+    while let Some(event) = viewer.events.get(viewer.next) {
+        if event.seconds > viewer.clock {
+            break;
+        }
+        if let Ok(action) = GuessUpdateAction::try_from(event.key.clone()) {
+            guess_writer.send(GuessUpdateEvent { action, key: event.key.clone() });
+        }
+        viewer.next += 1;
+    }
+}
+
+fn speed_message(speed: f64) -> String {
+    format!("Replay at {}x", speed)
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+/// How a single letter of a guess compares to the answer.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum LetterScore {
+    // letter is in the word and in the right spot.
+    Correct,
+    // letter is in the word, but in the wrong spot.
+    Present,
+    // letter is not in the word (or every copy of it was already matched).
+    Absent,
+}
+
+impl LetterScore {
+    /// how much a score tells about a letter.
+    /// Correct tells the most, Absent the least.
+    fn rank(&self) -> u8 {
+        match self {
+            LetterScore::Correct => 2,
+            LetterScore::Present => 1,
+            LetterScore::Absent => 0,
+        }
+    }
+}
+
+/// Best known score for every letter guessed so far.
+/// Used to paint the on screen keyboard.
+#[derive(Debug, Default, Clone)]
+pub struct LetterStates {
+    letters: HashMap<char, LetterScore>,
+}
+
+impl LetterStates {
+    /// records what a scored guess revealed.
+    /// a letter never goes down, once it is Correct it stays Correct.
+    pub fn update(&mut self, guess: &str, scores: &[LetterScore]) {
+        for (letter, score) in guess.chars().zip(scores) {
+            let best = self.letters.entry(letter).or_insert(*score);
+            if score.rank() > best.rank() {
+                *best = *score;
+            }
+        }
+    }
+
+    pub fn get(&self, letter: char) -> Option<LetterScore> {
+        self.letters.get(&letter).copied()
+    }
+}
+
+/// Scores a guess against the answer, one LetterScore per letter of the guess.
+///
+/// Two passes are needed so repeated letters are not over counted:
+/// 1. mark every letter in the right spot as Correct and keep track of
+///    the answer letters that were not matched.
+/// 2. walk the rest of the guess left to right, a letter is Present only
+///    while there are unmatched copies of it left in the answer.
+///
+/// EX: guess SPEED vs answer ABIDE -> S P E E D = absent absent present absent present.
+/// only one E is in ABIDE so only the first E gets to be Present.
+pub fn score_guess(guess: &str, answer: &str) -> Vec<LetterScore> {
+    let guess: Vec<char> = guess.chars().collect();
+    let answer: Vec<char> = answer.chars().collect();
+    let mut scores = vec![LetterScore::Absent; guess.len()];
+    // answer letters that were not in the right spot, and how many of each.
+    let mut unmatched: HashMap<char, usize> = HashMap::new();
+
+    // pass 1 - right letter, right spot.
+    for (i, a) in answer.iter().enumerate() {
+        match guess.get(i) {
+            Some(g) if g == a => scores[i] = LetterScore::Correct,
+            _ => *unmatched.entry(*a).or_insert(0) += 1,
+        }
+    }
+    // pass 2 - right letter, wrong spot.
+    for (i, g) in guess.iter().enumerate() {
+        if scores[i] == LetterScore::Correct {
+            continue;
+        }
+        if let Some(count) = unmatched.get_mut(g) {
+            if *count > 0 {
+                *count -= 1;
+                scores[i] = LetterScore::Present;
+            }
+        }
+    }
+    scores
+}
+
+/// true when every letter of the guess is in the right spot.
+pub fn is_solved(scores: &[LetterScore]) -> bool {
+    !scores.is_empty() && scores.iter().all(|score| *score == LetterScore::Correct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LetterScore::{Absent, Correct, Present};
+
+    #[test]
+    fn scores_every_letter() {
+        assert_eq!(score_guess("CRANE", "CRANE"), vec![Correct; 5]);
+        assert_eq!(score_guess("CRANE", "NACRE"), vec![Present, Present, Present, Present, Correct]);
+        assert_eq!(score_guess("BUMPY", "CRANE"), vec![Absent; 5]);
+    }
+
+    #[test]
+    fn only_one_repeated_letter_is_present_when_the_answer_has_one() {
+        // the example from the doc comment.
+        assert_eq!(
+            score_guess("SPEED", "ABIDE"),
+            vec![Absent, Absent, Present, Absent, Present],
+        );
+    }
+
+    #[test]
+    fn right_spot_is_matched_before_wrong_spot() {
+        // THEME has two Es, the last one is matched in place,
+        // which leaves one for the first E of the guess.
+        assert_eq!(
+            score_guess("EERIE", "THEME"),
+            vec![Present, Absent, Absent, Absent, Correct],
+        );
+    }
+
+    #[test]
+    fn is_solved_needs_every_letter_correct() {
+        assert!(is_solved(&[Correct; 5]));
+        assert!(!is_solved(&[Correct, Correct, Present, Correct, Correct]));
+        assert!(!is_solved(&[]));
+    }
+
+    #[test]
+    fn letter_states_never_go_down() {
+        let mut states = LetterStates::default();
+        states.update("CRANE", &[Correct, Absent, Present, Absent, Absent]);
+        assert_eq!(states.get('C'), Some(Correct));
+        assert_eq!(states.get('A'), Some(Present));
+        assert_eq!(states.get('Z'), None);
+        // a later guess that scores them lower doesn't take back what was known.
+        states.update("ACORN", &[Absent, Present, Absent, Absent, Absent]);
+        assert_eq!(states.get('C'), Some(Correct));
+        assert_eq!(states.get('A'), Some(Present));
+        // but a better score does go up.
+        states.update("CAROM", &[Correct, Correct, Absent, Absent, Absent]);
+        assert_eq!(states.get('A'), Some(Correct));
+    }
+}
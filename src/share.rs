@@ -0,0 +1,207 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use crate::game::GameStatus;
+use crate::mode::GameMode;
+use crate::multi::MultiGame;
+use crate::scoring::LetterScore;
+use crate::storage::data_dir;
+
+// written to the data directory when there's no clipboard.
+const SHARE_FILE: &str = "share.txt";
+
+// programs that can put text on the clipboard, tried in order.
+// they read the text from stdin.
+const CLIPBOARD_COMMANDS: [(&str, &[&str]); 5] = [
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("pbcopy", &[]),
+    ("clip.exe", &[]),
+];
+
+/// Where share put the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shared {
+    Clipboard,
+    File(PathBuf),
+    Stdout,
+}
+
+/// The finished game as spoiler free text to paste into a chat.
+///
+/// EX: a daily solved on the third guess in hard mode:
+/// Wordle Daily #120 3/6*
+/// ⬛🟨⬛⬛⬛
+/// 🟩⬛🟩🟨⬛
+/// 🟩🟩🟩🟩🟩
+///
+/// practice games show the seed instead of the puzzle number so the same word can be played again.
+/// with several boards the grids follow each other, a blank line between them.
+/// colorblind - orange and blue squares instead of green and yellow, like the game shows.
+pub fn share_text(
+    game: &MultiGame,
+    mode: GameMode,
+    puzzle: Option<u32>,
+    seed: u64,
+    colorblind: bool,
+) -> String {
+    let puzzle = match (mode, puzzle) {
+        (_, Some(puzzle)) => format!(" #{}", puzzle),
+        // absurdle plays out the same whatever the seed.
+        (GameMode::Absurdle, None) => String::new(),
+        (_, None) => format!(" seed {}", seed),
+    };
+    let score = match game.status() {
+        GameStatus::Won => game.guess_index().to_string(),
+        _ => "X".to_string(),
+    };
+    let mut text = format!(
+        "Wordle {}{} {}/{}{}\n",
+        mode,
+        puzzle,
+        score,
+        game.config().max_guesses,
+        if game.is_hard_mode() { "*" } else { "" },
+    );
+    let grids: Vec<String> = game
+        .boards()
+        .iter()
+        .map(|board| {
+            board
+                .rows()
+                .iter()
+                .map(|row| emoji_row(&row.scores, colorblind))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect();
+    text.push_str(&grids.join("\n\n"));
+    text
+}
+
+/// one square per letter, e.g. 🟩🟨⬛⬛⬛, or 🟧🟦⬛⬛⬛ when colorblind.
+pub fn emoji_row(scores: &[LetterScore], colorblind: bool) -> String {
+    scores
+        .iter()
+        .map(|score| match (score, colorblind) {
+            (LetterScore::Correct, false) => '🟩',
+            (LetterScore::Present, false) => '🟨',
+            (LetterScore::Correct, true) => '🟧',
+            (LetterScore::Present, true) => '🟦',
+            (LetterScore::Absent, _) => '⬛',
+        })
+        .collect()
+}
+
+/// copies the text to the clipboard.
+/// without one (e.g. no display) it's written to share.txt in the data directory,
+/// or printed to stdout when there's no data directory either.
+pub fn share(text: &str) -> io::Result<Shared> {
+    if CLIPBOARD_COMMANDS
+        .iter()
+        .any(|(program, args)| copy_with(program, args, text))
+    {
+        return Ok(Shared::Clipboard);
+    }
+    match data_dir() {
+        Some(dir) => {
+            fs::create_dir_all(&dir)?;
+            let path = dir.join(SHARE_FILE);
+            fs::write(&path, format!("{}\n", text))?;
+            Ok(Shared::File(path))
+        }
+        None => {
+            println!("{}", text);
+            Ok(Shared::Stdout)
+        }
+    }
+}
+
+/// true when the program was there and took the text.
+fn copy_with(program: &str, args: &[&str], text: &str) -> bool {
+    let child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        // not installed.
+        Err(_) => return false,
+    };
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    // stdin is closed by now, so the program knows the text is all there.
+    matches!(child.wait(), Ok(status) if status.success()) && written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::words::WordList;
+
+    fn played(answers: &[&str], hard_mode: bool, guesses: &[&str]) -> MultiGame {
+        let config = GameConfig::new(5, answers.len()).expect("expect a config for that many boards");
+        let answers: Vec<String> = answers.iter().map(|answer| answer.to_string()).collect();
+        let mut game = MultiGame::new(config, WordList::default(), &answers);
+        game.set_hard_mode(hard_mode);
+        for guess in guesses {
+            guess.chars().try_for_each(|letter| game.append(letter)).unwrap();
+            game.submit().unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn header_and_grid() {
+        let game = played(&["CRANE"], false, &["ALONE", "CRANE"]);
+        assert_eq!(
+            share_text(&game, GameMode::Daily, Some(120), 7, false),
+            "Wordle Daily #120 2/6\n🟨⬛⬛🟩🟩\n🟩🟩🟩🟩🟩",
+        );
+        // practice games show the seed.
+        assert_eq!(
+            share_text(&game, GameMode::Practice, None, 7, false),
+            "Wordle Practice seed 7 2/6\n🟨⬛⬛🟩🟩\n🟩🟩🟩🟩🟩",
+        );
+    }
+
+    #[test]
+    fn colorblind_squares() {
+        let game = played(&["CRANE"], false, &["ALONE", "CRANE"]);
+        assert_eq!(
+            share_text(&game, GameMode::Daily, Some(120), 7, true),
+            "Wordle Daily #120 2/6\n🟦⬛⬛🟧🟧\n🟧🟧🟧🟧🟧",
+        );
+    }
+
+    #[test]
+    fn hard_mode_gets_a_star() {
+        let game = played(&["CRANE"], true, &["CRANE"]);
+        assert_eq!(
+            share_text(&game, GameMode::Absurdle, None, 7, false),
+            "Wordle Absurdle 1/6*\n🟩🟩🟩🟩🟩",
+        );
+    }
+
+    #[test]
+    fn lost_games_and_several_boards() {
+        let guesses = ["CRANE", "DUMPY", "JUMPY", "LUMPY", "FIFTY", "WIDOW", "FROWN"];
+        let game = played(&["CRANE", "SLOTH"], false, &guesses);
+        let text = share_text(&game, GameMode::Practice, None, 7, false);
+        let (header, grids) = text.split_once('\n').unwrap();
+        assert_eq!(header, "Wordle Practice seed 7 X/7");
+        // the solved board stops at its answer.
+        let grids: Vec<&str> = grids.split("\n\n").collect();
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[0], "🟩🟩🟩🟩🟩");
+        assert_eq!(grids[1].lines().count(), 7);
+    }
+}
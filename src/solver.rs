@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use crate::game::{Game, ScoredGuess};
+use crate::scoring::{score_guess, LetterScore};
+use crate::words::WordList;
+
+/// How every word that can be guessed scores against every answer,
+/// each score boiled down to a single number (see pattern_code).
+///
+/// Scoring is the slow part of picking a guess, so it is done once per word list
+/// and shared, see PatternTable::for_words.
+#[derive(Debug)]
+pub struct PatternTable {
+    // answers first, then the other allowed guesses. an answer's index is the same in both.
+    guesses: Vec<String>,
+    answer_count: usize,
+    // guesses.len() rows of answer_count patterns.
+    patterns: Vec<u16>,
+    // best first guess, worked out the first time it's asked for.
+    opener: OnceLock<usize>,
+}
+
+impl PatternTable {
+    /// scores every guess against every answer in the list.
+    pub fn new(words: &WordList) -> Self {
+        let guesses: Vec<String> = words
+            .answers()
+            .iter()
+            .chain(words.allowed_guesses())
+            .cloned()
+            .collect();
+        let answers = words.answers();
+        let patterns = guesses
+            .iter()
+            .flat_map(|guess| {
+                answers
+                    .iter()
+                    .map(move |answer| pattern_code(&score_guess(guess, answer)))
+            })
+            .collect();
+        PatternTable {
+            guesses,
+            answer_count: answers.len(),
+            patterns,
+            opener: OnceLock::new(),
+        }
+    }
+
+    /// the table for a word list, built the first time it's asked for
+    /// and kept for as long as the program runs.
+    /// there is one list per word length, so the length is enough to tell them apart.
+    pub fn for_words(words: &WordList) -> Arc<Self> {
+        if let Some(table) = cached_tables().get(&words.word_length()) {
+            return table.clone();
+        }
+        // built without holding the lock, so looking up other tables doesn't wait on it.
+        // two threads might both build it, every caller still gets the one that went in first.
+        let table = Arc::new(PatternTable::new(words));
+        cached_tables()
+            .entry(words.word_length())
+            .or_insert(table)
+            .clone()
+    }
+
+    /// true once for_words has built the table for this word list.
+    pub fn is_cached(words: &WordList) -> bool {
+        cached_tables().contains_key(&words.word_length())
+    }
+
+    /// every word that can be guessed, answers first.
+    pub fn guesses(&self) -> &[String] {
+        &self.guesses
+    }
+
+    /// the answers, they are the first words of guesses.
+    pub fn answers(&self) -> &[String] {
+        &self.guesses[..self.answer_count]
+    }
+
+    /// pattern_code of guess scored against answer, both by index.
+    pub fn pattern(&self, guess: usize, answer: usize) -> u16 {
+        self.patterns[guess * self.answer_count + answer]
+    }
+
+    fn index_of(&self, guess: &str) -> Option<usize> {
+        self.guesses.iter().position(|word| word == guess)
+    }
+}
+
+/// every table for_words has built, by word length.
+fn cached_tables() -> MutexGuard<'static, HashMap<usize, Arc<PatternTable>>> {
+    static TABLES: OnceLock<Mutex<HashMap<usize, Arc<PatternTable>>>> = OnceLock::new();
+    TABLES
+        .get_or_init(Default::default)
+        .lock()
+        .expect("expect the pattern table cache not to be poisoned")
+}
+
+/// a whole scored guess as one number, one base 3 digit per letter.
+/// two guesses get the same code only when they scored the same.
+pub fn pattern_code(scores: &[LetterScore]) -> u16 {
+    scores.iter().rev().fold(0, |code, score| {
+        code * 3
+            + match score {
+                LetterScore::Absent => 0,
+                LetterScore::Present => 1,
+                LetterScore::Correct => 2,
+            }
+    })
+}
+
+/// Picks the guess expected to tell the most about the answer.
+///
+/// A guess splits the answers that are still possible into groups, one per pattern
+/// it would score. The solver picks the guess whose groups are the most even,
+/// (the highest entropy) so whatever the pattern turns out to be, few words are left.
+/// Doesn't need bevy, the terminal version and tools can use it too.
+#[derive(Debug, Clone)]
+pub struct Solver {
+    table: Arc<PatternTable>,
+    // answers that fit every guess so far, as indexes into the table.
+    candidates: Vec<usize>,
+    // every guess has to be a word that could still be the answer.
+    hard_mode: bool,
+}
+
+impl Solver {
+    /// every answer in the list is still possible.
+    pub fn new(words: &WordList) -> Self {
+        let table = PatternTable::for_words(words);
+        Solver {
+            candidates: (0..table.answer_count).collect(),
+            table,
+            hard_mode: false,
+        }
+    }
+
+    /// a solver that knows what the guesses of a game so far have shown.
+    pub fn for_game(game: &Game) -> Self {
+        let mut solver = Solver::new(game.words());
+        solver.hard_mode = game.is_hard_mode();
+        for row in game.rows() {
+            solver.add(row);
+        }
+        solver
+    }
+
+    /// only guesses that could still be the answer are picked from now on.
+    /// those always follow the hard mode rules.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.hard_mode = hard_mode;
+    }
+
+    /// answers that fit every guess so far, in word list order.
+    pub fn candidates(&self) -> Vec<&str> {
+        self.candidates
+            .iter()
+            .map(|index| self.table.guesses[*index].as_str())
+            .collect()
+    }
+
+    /// drops every answer that wouldn't have scored the guess this way.
+    pub fn add(&mut self, row: &ScoredGuess) {
+        let code = pattern_code(&row.scores);
+        let table = &self.table;
+        match table.index_of(&row.word) {
+            Some(guess) => self
+                .candidates
+                .retain(|answer| table.pattern(guess, *answer) == code),
+            // not in the table, e.g. a word from another list. scored the slow way.
+            None => self.candidates.retain(|answer| {
+                pattern_code(&score_guess(&row.word, &table.guesses[*answer])) == code
+            }),
+        }
+    }
+
+    /// how many bits a guess is expected to tell about the answer.
+    /// 0 for a word that isn't in the table or can't split the candidates.
+    pub fn entropy(&self, guess: &str) -> f64 {
+        let mut counts = vec![0; pattern_count(&self.table)];
+        self.table
+            .index_of(guess)
+            .map_or(0.0, |guess| self.entropy_of(guess, &mut counts))
+    }
+
+    /// the guess with the most expected information.
+    /// ties go to a word that could be the answer, then to the earlier word in the list.
+    /// None once no answer fits the guesses, e.g. they came from another word list.
+    pub fn best_guess(&self) -> Option<&str> {
+        let best = match self.candidates.as_slice() {
+            [] => return None,
+            // guessing one of the two is as good as it gets, it might be right.
+            [only] | [only, _] => *only,
+            _ if self.candidates.len() == self.table.answer_count && !self.hard_mode => {
+                *self.table.opener.get_or_init(|| self.pick_guess())
+            }
+            _ => self.pick_guess(),
+        };
+        Some(&self.table.guesses[best])
+    }
+
+    fn pick_guess(&self) -> usize {
+        let mut counts = vec![0; pattern_count(&self.table)];
+        let mut best = (self.candidates[0], f64::MIN, true);
+        let guesses: Vec<usize> = if self.hard_mode {
+            self.candidates.clone()
+        } else {
+            (0..self.table.guesses.len()).collect()
+        };
+        for guess in guesses {
+            let entropy = self.entropy_of(guess, &mut counts);
+            let candidate = self.candidates.binary_search(&guess).is_ok();
+            // a tiny margin so float noise doesn't decide ties.
+            let better = entropy > best.1 + 1e-9
+                || (entropy > best.1 - 1e-9 && candidate && !best.2);
+            if better {
+                best = (guess, entropy, candidate);
+            }
+        }
+        best.0
+    }
+
+    // counts is scratch space with a slot per pattern, left zeroed.
+    fn entropy_of(&self, guess: usize, counts: &mut [u32]) -> f64 {
+        for answer in &self.candidates {
+            counts[usize::from(self.table.pattern(guess, *answer))] += 1;
+        }
+        let total = self.candidates.len() as f64;
+        let mut entropy = 0.0;
+        for answer in &self.candidates {
+            let count = &mut counts[usize::from(self.table.pattern(guess, *answer))];
+            if *count > 0 {
+                let p = f64::from(*count) / total;
+                entropy -= p * p.log2();
+                *count = 0;
+            }
+        }
+        entropy
+    }
+}
+
+/// how many different patterns a guess can score, 3 per letter.
+fn pattern_count(table: &PatternTable) -> usize {
+    let word_length = table.guesses.first().map_or(0, String::len);
+    3usize.pow(word_length as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::GameConfig;
+    use crate::scoring::LetterScore::*;
+
+    #[test]
+    fn pattern_codes() {
+        assert_eq!(pattern_code(&[Absent; 5]), 0);
+        assert_eq!(pattern_code(&[Correct; 5]), 242);
+        // the first letter is the lowest digit.
+        assert_eq!(pattern_code(&[Present, Absent, Absent, Absent, Absent]), 1);
+        assert_eq!(pattern_code(&[Absent, Correct, Absent, Absent, Absent]), 6);
+    }
+
+    #[test]
+    fn pattern_table_matches_scoring() {
+        let words = WordList::default();
+        let table = PatternTable::for_words(&words);
+        assert!(PatternTable::is_cached(&words));
+        assert!(Arc::ptr_eq(&table, &PatternTable::for_words(&words)));
+        assert_eq!(table.answers(), words.answers());
+        assert_eq!(table.guesses().len(), words.answers().len() + words.allowed_guesses().len());
+        for guess in (0..table.guesses().len()).step_by(97) {
+            for answer in (0..table.answers().len()).step_by(13) {
+                let scores = score_guess(&table.guesses()[guess], &table.answers()[answer]);
+                assert_eq!(table.pattern(guess, answer), pattern_code(&scores));
+            }
+        }
+        for answer in 0..table.answers().len() {
+            assert_eq!(table.pattern(answer, answer), pattern_code(&[Correct; 5]));
+        }
+    }
+
+    #[test]
+    fn best_guess_has_the_most_entropy() {
+        let words = WordList::default();
+        let mut game = Game::new(GameConfig::default(), words.clone(), "CRANE");
+        "SLATE".chars().try_for_each(|letter| game.append(letter)).unwrap();
+        game.submit().unwrap();
+        let solver = Solver::for_game(&game);
+        let candidates = solver.candidates();
+        assert!(candidates.contains(&"CRANE") && candidates.len() > 2);
+        assert!(candidates
+            .iter()
+            .all(|answer| score_guess("SLATE", answer) == game.rows()[0].scores));
+
+        let best = solver.best_guess().unwrap();
+        let most = solver.entropy(best);
+        // no pattern can tell more than one bit per candidate split off.
+        assert!(most > 0.0 && most <= (candidates.len() as f64).log2() + 1e-9);
+        for guess in solver.table.guesses() {
+            assert!(solver.entropy(guess) <= most + 1e-9, "{} beats {}", guess, best);
+        }
+        assert_eq!(solver.entropy("QZXJK"), 0.0);
+    }
+
+    #[test]
+    fn plays_down_to_the_answer() {
+        let words = WordList::default();
+        for seed in 0..5 {
+            let mut game = Game::with_seed(GameConfig::default(), words.clone(), seed);
+            while !game.is_over() {
+                let guess = Solver::for_game(&game).best_guess().unwrap().to_string();
+                guess.chars().try_for_each(|letter| game.append(letter)).unwrap();
+                game.submit().unwrap();
+            }
+            assert_eq!(game.status(), crate::game::GameStatus::Won, "lost on {}", game.answer());
+        }
+        // two candidates left: guess one of them.
+        let mut solver = Solver::new(&words);
+        solver.candidates = vec![3, 10];
+        assert_eq!(solver.best_guess(), Some(words.answers()[3].as_str()));
+        solver.candidates.clear();
+        assert_eq!(solver.best_guess(), None);
+    }
+}
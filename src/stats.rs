@@ -0,0 +1,87 @@
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::game::GameStatus;
+use crate::multi::MultiGame;
+use crate::storage;
+
+// name of the stats files, see storage::config_file.
+const STATS_FILE: &str = "stats";
+
+/// How the player has done across every finished game.
+/// Every word length and number of boards keeps its own stats,
+/// the guess distribution wouldn't mean much with a different number of rows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    // stats from before there were other configs are for 5 letter words.
+    pub config: GameConfig,
+    pub played: u32,
+    pub wins: u32,
+    // wins in a row, reset by a loss.
+    pub current_streak: u32,
+    pub max_streak: u32,
+    // wins that were played in hard mode, also counted in wins.
+    pub hard_mode_wins: u32,
+    // guess_distribution[0] is how many wins took 1 guess,
+    // guess_distribution[1] how many took 2 and so on.
+    pub guess_distribution: Vec<u32>,
+    // daily puzzles that were finished with the help of a hint.
+    pub hinted_dailies: Vec<u32>,
+}
+
+impl Stats {
+    /// stats saved by earlier games played with the config.
+    /// a missing or corrupt file starts over with empty stats.
+    pub fn load(config: GameConfig) -> Self {
+        storage::load(&storage::config_file(STATS_FILE, config)).unwrap_or(Stats {
+            config,
+            ..Default::default()
+        })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        storage::save(&storage::config_file(STATS_FILE, self.config), self)
+    }
+
+    /// adds a finished game. games still in progress are ignored.
+    /// a game with several boards is won once every board is solved,
+    /// it goes in the distribution with the guesses that took.
+    pub fn record(&mut self, game: &MultiGame) {
+        match game.status() {
+            GameStatus::InProgress => return,
+            GameStatus::Won => {
+                let guesses = game.guess_index();
+                if self.guess_distribution.len() < guesses {
+                    self.guess_distribution.resize(guesses, 0);
+                }
+                self.guess_distribution[guesses - 1] += 1;
+                self.wins += 1;
+                if game.is_hard_mode() {
+                    self.hard_mode_wins += 1;
+                }
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+            }
+            GameStatus::Lost => self.current_streak = 0,
+        }
+        self.played += 1;
+    }
+
+    /// marks a daily puzzle as finished with hints.
+    pub fn mark_hinted_daily(&mut self, puzzle: u32) {
+        if !self.hinted_dailies.contains(&puzzle) {
+            self.hinted_dailies.push(puzzle);
+        }
+    }
+
+    /// percent of games won, rounded down.
+    pub fn win_percentage(&self) -> u32 {
+        if self.played == 0 {
+            return 0;
+        }
+        self.wins * 100 / self.played
+    }
+}
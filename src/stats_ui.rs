@@ -0,0 +1,339 @@
+use bevy::prelude::*;
+use wordle::config::GameConfig;
+use wordle::game::GameStatus;
+use wordle::share::{share, share_text, Shared};
+use wordle::stats::Stats;
+
+use crate::colorblind::ColorblindMode;
+use crate::colors::{Theme, ThemeColor};
+use crate::ui::{show_message, spawn_menu_button, MessageText};
+use crate::{FontSpec, GameContext, RunState};
+
+pub struct StatsUiPlugin;
+
+/// opens the stats panel, lives in the menu row next to the title.
+#[derive(Component)]
+pub struct StatsButton;
+/// root of the stats panel. despawning it closes the panel.
+#[derive(Component)]
+struct StatsPanel;
+#[derive(Component)]
+struct PlayAgainButton;
+#[derive(Component)]
+struct CloseStatsButton;
+/// copies the finished game to the clipboard, only on the game over panel.
+#[derive(Component)]
+struct ShareButton;
+
+impl Plugin for StatsUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system(stats_button_system)
+            .add_system(stats_panel_button_system)
+            .add_system(share_button_system)
+            .add_system_set(
+                SystemSet::on_enter(RunState::GameOver)
+                    // after game_over so the game that just ended is in the stats.
+                    .with_system(open_stats_on_game_over.after("game_over"))
+            )
+            .add_system_set(
+                SystemSet::on_enter(RunState::Playing)
+                    .with_system(close_stats_panel)
+                    .with_system(load_stats.after("game_reset"))
+            );
+    }
+}
+
+/// switches to the stats for the config of the game that was just set up.
+fn load_stats(
+    config: Res<GameConfig>,
+    mut stats: ResMut<Stats>,
+) {
+    if stats.config != *config {
+        *stats = Stats::load(*config);
+    }
+}
+
+fn stats_button_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<StatsButton>)>,
+    panel_query: Query<Entity, With<StatsPanel>>,
+    font_spec: Res<FontSpec>,
+    theme: Res<Theme>,
+    stats: Res<Stats>,
+    game_context: Res<GameContext>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked || !panel_query.is_empty() {
+            continue;
+        }
+        let highlight = highlighted_bar(&game_context);
+        let game_over = game_context.game.is_over();
+        spawn_stats_panel(&mut commands, &font_spec, &theme, &stats, &game_context.game.config(), highlight, game_over);
+    }
+}
+
+fn open_stats_on_game_over(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<StatsPanel>>,
+    font_spec: Res<FontSpec>,
+    theme: Res<Theme>,
+    stats: Res<Stats>,
+    game_context: Res<GameContext>,
+) {
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    let highlight = highlighted_bar(&game_context);
+    spawn_stats_panel(&mut commands, &font_spec, &theme, &stats, &game_context.game.config(), highlight, true);
+}
+
+fn close_stats_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<StatsPanel>>,
+) {
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn stats_panel_button_system(
+    mut commands: Commands,
+    play_again_query: Query<&Interaction, (Changed<Interaction>, With<PlayAgainButton>)>,
+    close_query: Query<&Interaction, (Changed<Interaction>, With<CloseStatsButton>)>,
+    panel_query: Query<Entity, With<StatsPanel>>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    let play_again = play_again_query.iter().any(|i| *i == Interaction::Clicked);
+    let close = close_query.iter().any(|i| *i == Interaction::Clicked);
+    if !play_again && !close {
+        return;
+    }
+    for entity in panel_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    // play again is only there once the game is over.
+    // entering Playing starts a new round.
+    if play_again && *run_state.current() == RunState::GameOver {
+        let _ = run_state.overwrite_set(RunState::Playing);
+    }
+}
+
+/// copies the result grid of the game that just ended and says where it went.
+fn share_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ShareButton>)>,
+    game_context: Res<GameContext>,
+    colorblind: Res<ColorblindMode>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    if !interaction_query.iter().any(|i| *i == Interaction::Clicked) {
+        return;
+    }
+    let text = share_text(
+        &game_context.game,
+        game_context.mode,
+        game_context.puzzle,
+        game_context.seed,
+        colorblind.0,
+    );
+    let message = match share(&text) {
+        Ok(Shared::Clipboard) => "Copied the result to the clipboard.".to_string(),
+        Ok(Shared::File(path)) => format!("No clipboard, the result was saved to {}.", path.display()),
+        Ok(Shared::Stdout) => "No clipboard, the result was printed to the terminal.".to_string(),
+        Err(error) => format!("Couldn't share the result: {}", error),
+    };
+    show_message(&mut message_query, &message);
+}
+
+/// index of the bar for the game that was just won, if there is one.
+fn highlighted_bar(game_context: &GameContext) -> Option<usize> {
+    // multi board games aren't in the stats.
+    let game = match game_context.game.boards() {
+        [game] => game,
+        _ => return None,
+    };
+    if game.status() != GameStatus::Won {
+        return None;
+    }
+    Some(game.rows().len() - 1)
+}
+
+fn spawn_stats_panel(
+    commands: &mut Commands,
+    font_spec: &FontSpec,
+    theme: &Theme,
+    stats: &Stats,
+    config: &GameConfig,
+    highlight: Option<usize>,
+    game_over: bool,
+) {
+    // one bar per possible number of guesses, even if the player never won with it.
+    let bars = stats.guess_distribution.len().max(config.max_guesses);
+    let most_wins = stats.guess_distribution.iter().copied().max().unwrap_or(0).max(1);
+    commands
+        .spawn_bundle(NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                position: Rect {
+                    left: Val::Percent(25.0),
+                    right: Val::Auto,
+                    top: Val::Percent(12.0),
+                    bottom: Val::Auto,
+                },
+                size: Size::new(Val::Percent(50.0), Val::Auto),
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                padding: Rect::all(Val::Px(20.0)),
+                ..Default::default()
+            },
+            color: UiColor(theme.panel),
+            ..Default::default()
+        })
+        .insert(ThemeColor::Panel)
+        .with_children(|panel| {
+            spawn_text(panel, font_spec, theme, "STATISTICS", 24.0);
+            // the stats are kept apart for every config.
+            let boards = match config.boards {
+                1 => String::new(),
+                boards => format!(", {} boards", boards),
+            };
+            spawn_text(panel, font_spec, theme, &format!("{} letters{}", config.word_length, boards), 16.0);
+            // played / win % / streaks
+            panel.spawn_bundle(NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(100.0), Val::Auto),
+                    justify_content: JustifyContent::SpaceAround,
+                    margin: Rect {
+                        left: Val::Px(0.0),
+                        right: Val::Px(0.0),
+                        top: Val::Px(10.0),
+                        bottom: Val::Px(20.0),
+                    },
+                    ..Default::default()
+                },
+                color: UiColor(Color::NONE),
+                ..Default::default()
+            })
+            .with_children(|row| {
+                let numbers = [
+                    (stats.played, "Played"),
+                    (stats.win_percentage(), "Win %"),
+                    (stats.current_streak, "Current Streak"),
+                    (stats.max_streak, "Max Streak"),
+                    (stats.hard_mode_wins, "Hard Wins"),
+                    (stats.hinted_dailies.len() as u32, "Hinted Dailies"),
+                ];
+                for (value, label) in numbers {
+                    row.spawn_bundle(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::ColumnReverse,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        color: UiColor(Color::NONE),
+                        ..Default::default()
+                    })
+                    .with_children(|number| {
+                        spawn_text(number, font_spec, theme, &value.to_string(), 32.0);
+                        spawn_text(number, font_spec, theme, label, 14.0);
+                    });
+                }
+            });
+            spawn_text(panel, font_spec, theme, "GUESS DISTRIBUTION", 20.0);
+            // one horizontal bar per number of guesses, sized by how many wins took that many.
+            for guesses in 0..bars {
+                let wins = stats.guess_distribution.get(guesses).copied().unwrap_or(0);
+                let bar_color = match highlight {
+                    Some(index) if index == guesses => ThemeColor::RightSpot,
+                    _ => ThemeColor::NotInWord,
+                };
+                panel.spawn_bundle(NodeBundle {
+                    style: Style {
+                        size: Size::new(Val::Percent(100.0), Val::Px(24.0)),
+                        align_items: AlignItems::Center,
+                        margin: Rect {
+                            left: Val::Px(0.0),
+                            right: Val::Px(0.0),
+                            top: Val::Px(4.0),
+                            bottom: Val::Px(0.0),
+                        },
+                        ..Default::default()
+                    },
+                    color: UiColor(Color::NONE),
+                    ..Default::default()
+                })
+                .with_children(|row| {
+                    spawn_text(row, font_spec, theme, &(guesses + 1).to_string(), 18.0);
+                    row.spawn_bundle(NodeBundle {
+                        style: Style {
+                            // an empty bar is still wide enough to show its 0.
+                            size: Size::new(
+                                Val::Percent(8.0 + 82.0 * wins as f32 / most_wins as f32),
+                                Val::Percent(100.0),
+                            ),
+                            justify_content: JustifyContent::FlexEnd,
+                            align_items: AlignItems::Center,
+                            margin: Rect {
+                                left: Val::Px(10.0),
+                                right: Val::Px(0.0),
+                                top: Val::Px(0.0),
+                                bottom: Val::Px(0.0),
+                            },
+                            padding: Rect {
+                                left: Val::Px(0.0),
+                                right: Val::Px(6.0),
+                                top: Val::Px(0.0),
+                                bottom: Val::Px(0.0),
+                            },
+                            ..Default::default()
+                        },
+                        color: UiColor(theme.color(bar_color)),
+                        ..Default::default()
+                    })
+                    .insert(bar_color)
+                    .with_children(|bar| {
+                        spawn_text(bar, font_spec, theme, &wins.to_string(), 16.0);
+                    });
+                });
+            }
+            panel.spawn_bundle(NodeBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    margin: Rect {
+                        left: Val::Px(0.0),
+                        right: Val::Px(0.0),
+                        top: Val::Px(20.0),
+                        bottom: Val::Px(0.0),
+                    },
+                    ..Default::default()
+                },
+                color: UiColor(Color::NONE),
+                ..Default::default()
+            })
+            .with_children(|row| {
+                // a game still being played can't be dropped from here,
+                // it would never go in the stats.
+                if game_over {
+                    spawn_menu_button(row, font_spec, theme, "Share", ShareButton);
+                    spawn_menu_button(row, font_spec, theme, "Play again", PlayAgainButton);
+                }
+                spawn_menu_button(row, font_spec, theme, "Close", CloseStatsButton);
+            });
+        })
+        .insert(StatsPanel);
+}
+
+fn spawn_text(builder: &mut ChildBuilder, font_spec: &FontSpec, theme: &Theme, value: &str, font_size: f32) {
+    builder.spawn_bundle(TextBundle {
+        text: Text::with_section(
+            value,
+            TextStyle {
+                font: font_spec.family.clone(),
+                font_size,
+                color: theme.panel_text,
+            },
+            TextAlignment::default(),
+        ),
+        ..Default::default()
+    })
+    .insert(ThemeColor::PanelText);
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::mode::GameMode;
+
+/// directory the game keeps its files in, e.g. ~/.local/share/wordle on Linux.
+pub fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("wordle"))
+}
+
+/// reads a json file from the data directory.
+/// a missing or unreadable file is treated the same as no file.
+pub fn load<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let text = fs::read_to_string(data_dir()?.join(file_name)).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+/// writes a json file to the data directory, creating the directory if needed.
+pub fn save<T: Serialize>(file_name: &str, value: &T) -> io::Result<()> {
+    let dir = data_dir().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, "no data directory on this system")
+    })?;
+    fs::create_dir_all(&dir)?;
+    let text = serde_json::to_string_pretty(value)?;
+    fs::write(dir.join(file_name), text)
+}
+
+/// deletes a file from the data directory. a file that isn't there is fine.
+pub fn remove(file_name: &str) -> io::Result<()> {
+    let path = match data_dir() {
+        Some(dir) => dir.join(file_name),
+        None => return Ok(()),
+    };
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+/// What is kept of a game wherever it's saved: the guesses and how it was played.
+/// The fields sit next to the ones of the save they're in, see SavedGame, DailyRecord and Replay.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameRecord {
+    // accepted guesses, in order.
+    pub guesses: Vec<String>,
+    #[serde(default)]
+    pub hard_mode: bool,
+    // saves from before other word lengths were playable are 5 letters.
+    #[serde(default)]
+    pub config: GameConfig,
+    // hints used so far.
+    #[serde(default)]
+    pub hints: u32,
+    // letters given as hints, as (board, position).
+    #[serde(default)]
+    pub revealed: Vec<(usize, usize)>,
+    // the bot made a guess, the game won't go in the stats.
+    #[serde(default)]
+    pub bot_played: bool,
+}
+
+/// the last daily puzzle that was played, finished or not.
+/// used to bring the board back instead of letting it be played twice,
+/// so starting the daily again can't wipe out the clues it already gave.
+/// every word length and number of boards has its own daily, so each one gets its own record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyRecord {
+    pub puzzle: u32,
+    #[serde(flatten)]
+    pub record: GameRecord,
+}
+
+impl DailyRecord {
+    /// the record for a puzzle, if that puzzle was already started.
+    pub fn load(puzzle: u32, config: GameConfig) -> Option<Self> {
+        load::<DailyRecord>(&config_file("daily", config)).filter(|record| record.puzzle == puzzle)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        save(&config_file("daily", self.record.config), self)
+    }
+}
+
+/// file for something kept apart for every word length and number of boards.
+/// e.g. daily.json for 5 letter words, daily4.json for 4 letter words,
+/// daily5x4.json for four 5 letter boards.
+pub fn config_file(name: &str, config: GameConfig) -> String {
+    let boards = match config.boards {
+        1 => String::new(),
+        boards => format!("x{}", boards),
+    };
+    if config.word_length == GameConfig::default().word_length && boards.is_empty() {
+        format!("{}.json", name)
+    } else {
+        format!("{}{}{}.json", name, config.word_length, boards)
+    }
+}
+
+const SAVED_GAME_FILE: &str = "saved_game.json";
+
+/// A game that isn't finished yet, so it can be picked up after a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub mode: GameMode,
+    // daily puzzle number, None in practice mode.
+    pub puzzle: Option<u32>,
+    // the answers are picked again from the seed.
+    pub seed: u64,
+    #[serde(flatten)]
+    pub record: GameRecord,
+    // the guess that was being typed in.
+    pub current: String,
+}
+
+impl SavedGame {
+    pub fn load() -> Option<Self> {
+        load(SAVED_GAME_FILE)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        save(SAVED_GAME_FILE, self)
+    }
+
+    /// nothing to resume, e.g. the game was finished.
+    pub fn clear() -> io::Result<()> {
+        remove(SAVED_GAME_FILE)
+    }
+}
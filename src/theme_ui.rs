@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+use wordle::cli::parse_option;
+use wordle::storage::data_dir;
+
+use crate::colorblind::ColorblindMode;
+use crate::colors::{Theme, ThemeColor};
+use crate::ui::{set_button_label, show_message, MessageText};
+
+// folder in the data directory theme files are loaded from.
+const THEME_DIR: &str = "themes";
+
+pub struct ThemePlugin;
+
+/// switches to the next theme.
+#[derive(Component)]
+pub struct ThemeButton;
+
+/// Every theme that can be switched to, the built in ones first.
+struct Themes {
+    // each theme with the keys its file was missing, empty for the built in ones.
+    themes: Vec<(Theme, Vec<String>)>,
+    // index of the theme in use.
+    current: usize,
+}
+
+impl Themes {
+    /// the built in themes and every .ron file in the themes folder of the data directory.
+    /// `--theme <name|file>` picks the one to start with, a file is loaded too.
+    fn load() -> Self {
+        let mut themes: Vec<(Theme, Vec<String>)> = Theme::built_in()
+            .into_iter()
+            .map(|theme| (theme, Vec::new()))
+            .collect();
+        let mut files: Vec<_> = data_dir()
+            .and_then(|dir| fs::read_dir(dir.join(THEME_DIR)).ok())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "ron"))
+            .collect();
+        // same order every launch.
+        files.sort();
+        themes.extend(files.iter().filter_map(|path| load_theme(path)));
+        let mut current = 0;
+        if let Some(choice) = parse_option("--theme") {
+            let path = Path::new(&choice);
+            let loaded = if path.is_file() { load_theme(path) } else { None };
+            if let Some(loaded) = loaded {
+                themes.push(loaded);
+                current = themes.len() - 1;
+            } else {
+                match themes.iter().position(|(theme, _)| theme.name.eq_ignore_ascii_case(&choice)) {
+                    Some(index) => current = index,
+                    None => warn!("there is no theme called {}", choice),
+                }
+            }
+        }
+        Themes { themes, current }
+    }
+
+    /// the theme in use, in colorblind colors when that's on.
+    fn theme(&self, colorblind: &ColorblindMode) -> Theme {
+        let theme = self.themes[self.current].0.clone();
+        if colorblind.0 { theme.colorblind() } else { theme }
+    }
+}
+
+/// loads a theme file, a file that can't be read is left out.
+fn load_theme(path: &Path) -> Option<(Theme, Vec<String>)> {
+    match Theme::load(path) {
+        Ok((theme, missing)) => {
+            if !missing.is_empty() {
+                warn!("{}: {}", path.display(), missing_message(&theme, &missing));
+            }
+            Some((theme, missing))
+        }
+        Err(error) => {
+            warn!("couldn't load the theme {}: {}", path.display(), error);
+            None
+        }
+    }
+}
+
+fn missing_message(theme: &Theme, missing: &[String]) -> String {
+    format!("{} has no {}, the defaults are used", theme.name, missing.join(", "))
+}
+
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        let themes = Themes::load();
+        let theme = themes.themes[themes.current].0.clone();
+        // in before the startup systems, they draw with it.
+        app.insert_resource(ClearColor(theme.background))
+            .insert_resource(theme)
+            .insert_resource(themes)
+            .add_system(theme_button_system)
+            .add_system(theme_text_system)
+            .add_system(palette_system)
+            .add_system(theme_color_system);
+    }
+}
+
+/// switches to the next theme, and says which colors it was missing.
+fn theme_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ThemeButton>)>,
+    mut themes: ResMut<Themes>,
+    mut theme: ResMut<Theme>,
+    colorblind: Res<ColorblindMode>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        themes.current = (themes.current + 1) % themes.themes.len();
+        *theme = themes.theme(&colorblind);
+        let (next, missing) = &themes.themes[themes.current];
+        let message = if missing.is_empty() { String::new() } else { missing_message(next, missing) };
+        show_message(&mut message_query, &message);
+    }
+}
+
+/// switches the theme to colorblind colors and back.
+fn palette_system(
+    colorblind: Res<ColorblindMode>,
+    themes: Res<Themes>,
+    mut theme: ResMut<Theme>,
+) {
+    // the theme is already right on the first frame.
+    if colorblind.is_changed() && !colorblind.is_added() {
+        *theme = themes.theme(&colorblind);
+    }
+}
+
+/// keeps the theme button showing the theme in use.
+fn theme_text_system(
+    theme: Res<Theme>,
+    button_query: Query<&Children, With<ThemeButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+    let label = format!("Theme: {}", theme.name);
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, &label);
+    }
+}
+
+/// repaints everything drawn with a theme color when the theme changes.
+/// keyboard keys that show a score are repainted by the keyboard_color_system.
+fn theme_color_system(
+    theme: Res<Theme>,
+    mut clear_color: ResMut<ClearColor>,
+    mut node_query: Query<(&ThemeColor, &mut UiColor)>,
+    mut sprite_query: Query<(&ThemeColor, &mut Sprite)>,
+    mut text_query: Query<(&ThemeColor, &mut Text)>,
+) {
+    if !theme.is_changed() {
+        return;
+    }
+    clear_color.0 = theme.background;
+    for (role, mut color) in node_query.iter_mut() {
+        color.0 = theme.color(*role);
+    }
+    for (role, mut sprite) in sprite_query.iter_mut() {
+        sprite.color = theme.color(*role);
+    }
+    for (role, mut text) in text_query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            section.style.color = theme.color(*role);
+        }
+    }
+}
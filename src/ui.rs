@@ -1,30 +1,67 @@
-use crate::colors::{KEYBOARD_MATERIALS,KeyboardMaterials, MATERIALS};
-use crate::{FontSpec, GameContext, Board, Position, GuessUpdateAction, GuessUpdateEvent};
+use crate::colors::{Theme, ThemeColor};
+use crate::{FontSpec, GameContext, Board, Position, GuessUpdateAction, GuessUpdateEvent, RunState, board_grid};
+use wordle::config::{GameConfig, BOARD_COUNTS};
+use wordle::keyboard::*;
+use wordle::mode::GameMode;
+use crate::animation::{MotionButton, Revealing, TileAnimation};
+use crate::bot_ui::BotButton;
+use crate::colorblind::{key_marker_style, ColorblindButton, ColorblindMode, KeyMarker};
+use crate::hint_ui::HintButton;
+use crate::stats_ui::StatsButton;
+use crate::theme_ui::ThemeButton;
+use bevy::input::{keyboard::KeyboardInput, ElementState};
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
-const KEYBOARD_LETTERS: [&str; 28] = [
-    "Q", "W", "E", "R", "T", "Y", "U", "I", "O", "P",
-    "A", "S", "D", "F", "G", "H", "J", "K", "L",
-    "ENTER", "Z", "X", "C", "V", "B", "N", "M", "<-"
-    ];
-
-pub const ENTER_KEY: &str = "ENTER";
-pub const BACK_KEY: &str = "<-";
 pub struct GameUiPlugin;
 #[derive(Component)]
 pub struct MessageText;
+/// a key on the on screen keyboard.
+#[derive(Component)]
+struct KeyboardButton;
+/// part of a keyboard key colored for one board.
+/// keys are split into one part per board, laid out like the boards are.
+#[derive(Component)]
+struct KeyQuadrant {
+    board: usize,
+    // letter of the key, None for ENTER and <-.
+    letter: Option<char>,
+}
+/// switches between daily, practice and absurdle mode.
+#[derive(Component)]
+struct ModeButton;
+/// turns hard mode on and off.
+#[derive(Component)]
+struct HardModeButton;
+/// switches to the next word length.
+#[derive(Component)]
+struct WordLengthButton;
+/// switches to the next number of boards.
+#[derive(Component)]
+struct BoardCountButton;
 
 
 impl Plugin for GameUiPlugin {
     fn build(&self, app: &mut App,) {
         app.add_startup_system(setup_ui)
-            .add_system(keyboard_button_interaction_system);
+            .add_system(keyboard_button_interaction_system)
+            .add_system(physical_keyboard_system)
+            .add_system(mode_button_system)
+            .add_system(mode_text_system)
+            .add_system(hard_mode_button_system)
+            .add_system(hard_mode_text_system)
+            .add_system(word_length_button_system)
+            .add_system(word_length_text_system)
+            .add_system(board_count_button_system)
+            .add_system(board_count_text_system)
+            .add_system(keyboard_color_system);
     }
 }
 
 fn setup_ui(
     mut commands: Commands,
     font_spec: Res<FontSpec>,
+    theme: Res<Theme>,
 ) {
     // spawn the camera so people can see it lol
     commands.spawn_bundle(UiCameraBundle::default());
@@ -53,7 +90,7 @@ fn setup_ui(
                 justify_content: JustifyContent::Center,
                 ..Default::default()
             },
-            color: UiColor(MATERIALS.none),
+            color: UiColor(Color::NONE),
             ..Default::default()
         })
         .with_children(|parent| {
@@ -70,7 +107,7 @@ fn setup_ui(
                     },
                     ..Default::default()
                 },
-                color: UiColor(MATERIALS.none),
+                color: UiColor(Color::NONE),
                 ..Default::default()
             }).with_children(|builder| {
                 builder.spawn_bundle(TextBundle {
@@ -79,7 +116,7 @@ fn setup_ui(
                         TextStyle {
                             font: font_spec.family.clone(),
                             font_size: 40.0,
-                            color: Color::WHITE,
+                            color: theme.text,
                         },
                         TextAlignment::default(),
                     ),
@@ -88,7 +125,19 @@ fn setup_ui(
                         ..Default::default()
                     },
                     ..Default::default()
-                });
+                })
+                .insert(ThemeColor::Text);
+                // game mode, click to switch between daily, practice and absurdle
+                spawn_menu_button(builder, &font_spec, &theme, "", ModeButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", HardModeButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", WordLengthButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", BoardCountButton);
+                spawn_menu_button(builder, &font_spec, &theme, "Hint", HintButton);
+                spawn_menu_button(builder, &font_spec, &theme, "Bot", BotButton);
+                spawn_menu_button(builder, &font_spec, &theme, "Stats", StatsButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", MotionButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", ThemeButton);
+                spawn_menu_button(builder, &font_spec, &theme, "", ColorblindButton);
             });
             // message display container
             parent.spawn_bundle(NodeBundle {
@@ -97,7 +146,7 @@ fn setup_ui(
                     ..Default::default()
                 },
                 transform: Transform::from_xyz(0.0, 0.0, 1.0),
-                color: UiColor(MATERIALS.none),
+                color: UiColor(Color::NONE),
                 ..Default::default()
             }).with_children(|builder| {
                 builder.spawn_bundle(NodeBundle {
@@ -117,7 +166,7 @@ fn setup_ui(
                         ..Default::default()
                     },
                     transform: Transform::from_xyz(0.0, 0.0, 1.0),
-                    color: UiColor(MATERIALS.none),
+                    color: UiColor(Color::NONE),
                     ..Default::default()
                 }).with_children(|builder| {
                     builder.spawn_bundle(TextBundle {
@@ -126,25 +175,27 @@ fn setup_ui(
                             TextStyle {
                                 font: font_spec.family.clone(),
                                 font_size: 20.0,
-                                color: Color::WHITE
+                                color: theme.text,
                             },
                             TextAlignment::default(),
                         ),
                         ..Default::default()
-                    });
+                    })
+                    .insert(ThemeColor::Text);
                     builder.spawn_bundle(TextBundle {
                         text: Text::with_section(
                             "{Default Text}",
                             TextStyle {
                                 font: font_spec.family.clone(),
                                 font_size: 20.0,
-                                color: Color::WHITE
+                                color: theme.text,
                             },
                             TextAlignment::default(),
                         ),
                         ..Default::default()
                     })
-                    .insert(MessageText);
+                    .insert(MessageText)
+                    .insert(ThemeColor::Text);
                 });
             });
         });
@@ -162,9 +213,10 @@ fn setup_ui(
             0.0,
             0.0
         ),
-        color: UiColor(Color::WHITE),
+        color: UiColor(theme.keyboard),
         ..Default::default()
     })
+    .insert(ThemeColor::Keyboard)
     .with_children(|kb_builder| {
         // keyboard tiles
         // row 1
@@ -181,13 +233,14 @@ fn setup_ui(
                     justify_content: JustifyContent::Center,
                     ..Default::default()
                 },
-                color: UiColor(Color::WHITE),
+                color: UiColor(theme.keyboard),
                 ..Default::default()
             })
+            .insert(ThemeColor::Keyboard)
             .with_children(|builder| {
-                for key_index in 0..=9 {
+                for key_index in KEYBOARD_ROWS[0].clone() {
                     // call spawn_keyboard_key
-                    spawn_keyboard_button(builder, &font_spec, key_index);
+                    spawn_keyboard_button(builder, &font_spec, &theme, key_index);
                 }
             });
             // row 2
@@ -204,13 +257,14 @@ fn setup_ui(
                     justify_content: JustifyContent::Center,
                     ..Default::default()
                 },
-                color: UiColor(Color::WHITE),
+                color: UiColor(theme.keyboard),
                 ..Default::default()
             })
+            .insert(ThemeColor::Keyboard)
             .with_children(|builder| {
-                for key_index in 10..=18 {
+                for key_index in KEYBOARD_ROWS[1].clone() {
                     // call spawn_keyboard_key
-                    spawn_keyboard_button(builder, &font_spec, key_index);
+                    spawn_keyboard_button(builder, &font_spec, &theme, key_index);
                 }
             });
             // row 3
@@ -221,13 +275,14 @@ fn setup_ui(
                     justify_content: JustifyContent::Center,
                     ..Default::default()
                 },
-                color: UiColor(Color::WHITE),
+                color: UiColor(theme.keyboard),
                 ..Default::default()
             })
+            .insert(ThemeColor::Keyboard)
             .with_children(|builder| {
-                for key_index in 19..=27 {
+                for key_index in KEYBOARD_ROWS[2].clone() {
                     // call spawn_keyboard_key
-                    spawn_keyboard_button(builder, &font_spec, key_index);
+                    spawn_keyboard_button(builder, &font_spec, &theme, key_index);
                 }
             });
         });
@@ -235,9 +290,89 @@ fn setup_ui(
 }
 
 
+/// replaces the text shown on the message board.
+pub fn show_message(
+    message_query: &mut Query<&mut Text, With<MessageText>>,
+    message: &str,
+) {
+    let mut msg_text = message_query
+        .get_single_mut()
+        .expect("expect message text to exist.");
+    let msg_section = msg_text.sections
+        .first_mut()
+        .expect("expect first text section to be accessible as mutable");
+    msg_section.value = message.to_string();
+}
+
+/// replaces the text of a button spawned by spawn_menu_button.
+pub fn set_button_label(
+    children: &Children,
+    text_query: &mut Query<&mut Text>,
+    label: &str,
+) {
+    let mut text = text_query.get_mut(*children.first().expect(
+        "expect button have a first child."
+    ))
+    .unwrap();
+    let text_section = text.sections.first_mut()
+        .expect("expect first section to be accessible as mutable");
+    text_section.value = label.to_string();
+}
+
+/// a small text button, used in the menu row and on panels.
+/// its only child is the text. keyboard buttons are different, their text comes last.
+pub fn spawn_menu_button(
+    builder: &mut ChildBuilder,
+    font_spec: &FontSpec,
+    theme: &Theme,
+    label: &str,
+    marker: impl Component,
+) {
+    builder.spawn_bundle(ButtonBundle {
+        style: Style {
+            align_self: AlignSelf::Center,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            margin: Rect {
+                left: Val::Px(20.0),
+                right: Val::Px(0.0),
+                top: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+            },
+            padding: Rect {
+                left: Val::Px(10.0),
+                right: Val::Px(10.0),
+                top: Val::Px(5.0),
+                bottom: Val::Px(5.0),
+            },
+            ..Default::default()
+        },
+        color: UiColor(theme.key),
+        ..Default::default()
+    })
+    .with_children(|parent| {
+        parent.spawn_bundle(TextBundle {
+            text: Text::with_section(
+                label,
+                TextStyle {
+                    font: font_spec.family.clone(),
+                    font_size: 20.0,
+                    color: theme.key_letter,
+                },
+                Default::default()
+            ),
+            ..Default::default()
+        })
+        .insert(ThemeColor::KeyLetter);
+    })
+    .insert(ThemeColor::Key)
+    .insert(marker);
+}
+
 fn spawn_keyboard_button(
     commands: &mut ChildBuilder,
     font_spec: &Res<FontSpec>,
+    theme: &Theme,
     pos: usize,
 ) {
     commands
@@ -254,10 +389,50 @@ fn spawn_keyboard_button(
                     },
                     ..Default::default()
                 },
-                color: UiColor(KEYBOARD_MATERIALS.kb_btn_background),
+                color: UiColor(theme.key),
                 ..Default::default()
             })
+        .insert(ThemeColor::Key)
         .with_children(|parent| {
+            // single letter keys only.
+            let mut chars = KEYBOARD_LETTERS[pos].chars();
+            let letter = match (chars.next(), chars.next()) {
+                (Some(letter), None) => Some(letter),
+                _ => None,
+            };
+            // one part per board, the keyboard_color_system sizes and colors them.
+            // spawned before the text so the letter is drawn on top.
+            for board in 0..BOARD_COUNTS[BOARD_COUNTS.len() - 1] {
+                parent
+                    .spawn_bundle(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            display: Display::None,
+                            ..Default::default()
+                        },
+                        color: UiColor(theme.key),
+                        // clicks go through to the button.
+                        focus_policy: FocusPolicy::Pass,
+                        ..Default::default()
+                    })
+                    .with_children(|quadrant| {
+                        // shown in colorblind mode, see key_marker_style.
+                        quadrant
+                            .spawn_bundle(NodeBundle {
+                                style: Style {
+                                    position_type: PositionType::Absolute,
+                                    display: Display::None,
+                                    ..Default::default()
+                                },
+                                color: UiColor(theme.key_letter),
+                                focus_policy: FocusPolicy::Pass,
+                                ..Default::default()
+                            })
+                            .insert(KeyMarker)
+                            .insert(ThemeColor::KeyLetter);
+                    })
+                    .insert(KeyQuadrant { board, letter });
+            }
             parent
                 .spawn_bundle(TextBundle {
                     text: Text::with_section(
@@ -265,35 +440,44 @@ fn spawn_keyboard_button(
                         TextStyle {
                             font: font_spec.family.clone(),
                             font_size: 20.0,
-                            color: KEYBOARD_MATERIALS.kb_btn_letter
+                            color: theme.key_letter,
                         },
                         Default::default()
                     ),
                     ..Default::default()
-                });
-            });
+                })
+                .insert(ThemeColor::KeyLetter);
+            })
+        .insert(KeyboardButton);
 }
 
 fn keyboard_button_interaction_system(
     interaction_query: Query<
         (&Interaction, &Children),
-        (Changed<Interaction>, With<Button>)
+        (Changed<Interaction>, With<KeyboardButton>)
     >,
     text_query: Query<&Text>,
     mut guess_writer: EventWriter<GuessUpdateEvent>,
     game_context: Res<GameContext>,
+    config: Res<GameConfig>,
+    revealing: Res<Revealing>,
+    mut run_state: ResMut<State<RunState>>,
 ) {
+    // the keys of a replay come from the file.
+    if game_context.replaying {
+        return;
+    }
+    let mut guess_len = current_guess_len(&game_context);
+    let busy = revealing.0 || game_context.game_over_pending;
     for (interaction, children) in
     interaction_query.iter() {
         match interaction {
             // only handling clicked events here..
             Interaction::Clicked => {
-                    let guess_index = game_context.get_guess_index();
-                    let guess = &game_context.guess_collection[guess_index];
-                    // keyboard_button entity implemented such that the 1st child
+                    // keyboard_button entity implemented such that the last child
                     // is the TextBundle.
-                    let text = text_query.get(*children.first().expect(
-                        "expect button have a first child."
+                    let text = text_query.get(*children.last().expect(
+                        "expect button have a last child."
                     ))
                     .unwrap();
                     // determine the kind of GuessUpdateAction taking place
@@ -301,34 +485,327 @@ fn keyboard_button_interaction_system(
                     let text_section = text.sections.first()
                         .expect("Expect first section to be accessible as reference");
                     let key = text_section.value.to_string();
-                    // turn key pressed in to a GuessUpdateAction
-                    let update_action = GuessUpdateAction::try_from(text_section.value.to_string()).ok();
-                    if let Some(action) = update_action {
-                        // validate whether a guess update can happen
-                        // based on the action.
-                        match action {
-                            GuessUpdateAction::Append => {
-                                if guess.len() > 4 {
-                                    // max len is 5.
-                                    // greater than 4 no good
-                                    continue;
-                                }
-                                guess_writer.send(GuessUpdateEvent{action: GuessUpdateAction::Append, key})
-                            }
-                            GuessUpdateAction::Delete => {
-                                if guess.len() < 1 {
-                                    // nothing to delete for the guess
-                                    continue;
-                                }
-                                guess_writer.send(GuessUpdateEvent{action: GuessUpdateAction::Delete, key})
-                            }
-                            GuessUpdateAction::Submit => {
-                                guess_writer.send(GuessUpdateEvent{action: GuessUpdateAction::Submit, key})
-                            }
-                        }
+                    if let Some(event) = validate_key_press(key, &config, &mut guess_len, busy, &mut run_state) {
+                        guess_writer.send(event);
                     }
                 }
                 _ => ()
             }
     }
-}
\ No newline at end of file
+}
+
+/// letters, Backspace and Enter typed on a real keyboard.
+/// they go through the same validation as the on screen keyboard.
+fn physical_keyboard_system(
+    mut keyboard_reader: EventReader<KeyboardInput>,
+    mut guess_writer: EventWriter<GuessUpdateEvent>,
+    game_context: Res<GameContext>,
+    config: Res<GameConfig>,
+    revealing: Res<Revealing>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    if game_context.replaying {
+        return;
+    }
+    let mut guess_len = current_guess_len(&game_context);
+    let busy = revealing.0 || game_context.game_over_pending;
+    for input in keyboard_reader.iter() {
+        if input.state != ElementState::Pressed {
+            continue;
+        }
+        let key = match input.key_code.and_then(key_code_to_key) {
+            Some(key) => key,
+            // not a key the game uses.
+            None => continue,
+        };
+        if let Some(event) = validate_key_press(key.to_string(), &config, &mut guess_len, busy, &mut run_state) {
+            guess_writer.send(event);
+        }
+    }
+}
+
+/// length of the guess currently being entered.
+fn current_guess_len(game_context: &GameContext) -> usize {
+    game_context.game.current_guess().len()
+}
+
+/// decides whether a pressed key can update the guess.
+/// every input path goes through here so the rules only live in one place.
+///
+/// guess_len is the length of the guess including the keys already
+/// accepted this frame. It is kept up to date so several key presses
+/// in the same frame can't overflow the guess.
+/// busy - true while the last guess is still flipping over, or the game is about to end.
+/// keys are dropped until it's done.
+fn validate_key_press(
+    key: String,
+    config: &GameConfig,
+    guess_len: &mut usize,
+    busy: bool,
+    run_state: &mut State<RunState>,
+) -> Option<GuessUpdateEvent> {
+    if busy {
+        return None;
+    }
+    if *run_state.current() == RunState::GameOver {
+        // no more guesses once the game is over.
+        // ENTER starts a new round.
+        if key == ENTER_KEY {
+            // ignore a second ENTER in the same frame, the new round is already queued.
+            let _ = run_state.set(RunState::Playing);
+        }
+        return None;
+    }
+    // turn key pressed in to a GuessUpdateAction
+    let action = GuessUpdateAction::try_from(key.clone()).ok()?;
+    // validate whether a guess update can happen
+    // based on the action.
+    match action {
+        GuessUpdateAction::Append => {
+            if *guess_len >= config.word_length {
+                // guess is already full.
+                return None;
+            }
+            *guess_len += 1;
+        }
+        GuessUpdateAction::Delete => {
+            if *guess_len < 1 {
+                // nothing to delete for the guess
+                return None;
+            }
+            *guess_len -= 1;
+        }
+        GuessUpdateAction::Submit => (),
+    }
+    Some(GuessUpdateEvent{action, key})
+}
+
+/// maps a physical key to the label of the matching on screen key.
+fn key_code_to_key(key_code: KeyCode) -> Option<&'static str> {
+    let key = match key_code {
+        KeyCode::Back => BACK_KEY,
+        KeyCode::Return | KeyCode::NumpadEnter => ENTER_KEY,
+        KeyCode::A => "A", KeyCode::B => "B", KeyCode::C => "C",
+        KeyCode::D => "D", KeyCode::E => "E", KeyCode::F => "F",
+        KeyCode::G => "G", KeyCode::H => "H", KeyCode::I => "I",
+        KeyCode::J => "J", KeyCode::K => "K", KeyCode::L => "L",
+        KeyCode::M => "M", KeyCode::N => "N", KeyCode::O => "O",
+        KeyCode::P => "P", KeyCode::Q => "Q", KeyCode::R => "R",
+        KeyCode::S => "S", KeyCode::T => "T", KeyCode::U => "U",
+        KeyCode::V => "V", KeyCode::W => "W", KeyCode::X => "X",
+        KeyCode::Y => "Y", KeyCode::Z => "Z",
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// paints every keyboard button with the best known score for its letter.
+/// letters that haven't been guessed yet (and ENTER/<-) keep the default background.
+/// with several boards each key is split into parts, one per board.
+/// in colorblind mode each part gets the marker for its score too.
+/// a guess only shows on the keys once its row has flipped over.
+fn keyboard_color_system(
+    game_context: Res<GameContext>,
+    theme: Res<Theme>,
+    colorblind: Res<ColorblindMode>,
+    revealing: Res<Revealing>,
+    animations: Query<&TileAnimation>,
+    mut quadrant_query: Query<(&KeyQuadrant, &Children, &mut Style, &mut UiColor)>,
+    mut marker_query: Query<&mut Style, (With<KeyMarker>, Without<KeyQuadrant>)>,
+) {
+    if !game_context.is_changed() && !theme.is_changed() && !colorblind.is_changed() && !revealing.is_changed() {
+        return;
+    }
+    if animations.iter().any(TileAnimation::is_revealing) {
+        return;
+    }
+    let game = &game_context.game;
+    let (grid_columns, grid_rows) = board_grid(&game.config());
+    let width = 100.0 / grid_columns as f32;
+    let height = 100.0 / grid_rows as f32;
+    for (quadrant, markers, mut style, mut color) in quadrant_query.iter_mut() {
+        let board = match game.boards().get(quadrant.board) {
+            Some(board) => board,
+            // more parts than boards being played.
+            None => {
+                style.display = Display::None;
+                continue;
+            }
+        };
+        let column = (quadrant.board % grid_columns) as f32;
+        // bevy ui has y going up, like the ColumnReverse containers.
+        // the first row of boards is at the top.
+        let row = (grid_rows - 1 - quadrant.board / grid_columns) as f32;
+        style.display = Display::Flex;
+        style.size = Size::new(Val::Percent(width), Val::Percent(height));
+        style.position = Rect {
+            left: Val::Percent(column * width),
+            right: Val::Auto,
+            top: Val::Percent(row * height),
+            bottom: Val::Auto,
+        };
+        let role = quadrant.letter
+            .and_then(|letter| board.letter_states().get(letter))
+            .map(ThemeColor::for_score);
+        *color = UiColor(match role {
+            Some(role) => theme.color(role),
+            None => theme.key,
+        });
+        for marker in markers.iter() {
+            if let Ok(mut marker_style) = marker_query.get_mut(*marker) {
+                key_marker_style(&mut marker_style, role, colorblind.0);
+            }
+        }
+    }
+}
+
+/// switches between daily, practice and absurdle mode and starts a game in the new mode.
+fn mode_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ModeButton>)>,
+    mut game_context: ResMut<GameContext>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        game_context.mode = match game_context.mode {
+            GameMode::Daily => GameMode::Practice,
+            GameMode::Practice => GameMode::Absurdle,
+            GameMode::Absurdle => GameMode::Daily,
+        };
+        // entering Playing again resets the board for the new mode.
+        match run_state.current() {
+            RunState::Playing => run_state.overwrite_restart(),
+            RunState::GameOver => {
+                let _ = run_state.overwrite_set(RunState::Playing);
+            }
+        }
+    }
+}
+
+/// keeps the mode next to the title up to date.
+fn mode_text_system(
+    game_context: Res<GameContext>,
+    button_query: Query<&Children, With<ModeButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !game_context.is_changed() {
+        return;
+    }
+    let label = game_context.mode_label();
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, &label);
+    }
+}
+
+/// flips the hard mode setting.
+/// the current game only changes along with it if no guess was made yet.
+fn hard_mode_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<HardModeButton>)>,
+    mut game_context: ResMut<GameContext>,
+    mut message_query: Query<&mut Text, With<MessageText>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        game_context.hard_mode = !game_context.hard_mode;
+        let hard_mode = game_context.hard_mode;
+        if game_context.game.is_over() || !game_context.game.set_hard_mode(hard_mode) {
+            show_message(&mut message_query, "Hard mode changes with the next game.");
+        }
+    }
+}
+
+/// keeps the hard mode button showing the setting.
+fn hard_mode_text_system(
+    game_context: Res<GameContext>,
+    button_query: Query<&Children, With<HardModeButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !game_context.is_changed() {
+        return;
+    }
+    let label = if game_context.hard_mode {
+        "Hard: On"
+    } else {
+        "Hard: Off"
+    };
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, label);
+    }
+}
+
+/// plays the next word length, the board is resized when the new game starts.
+fn word_length_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<WordLengthButton>)>,
+    mut config: ResMut<GameConfig>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        *config = config.next_word_length();
+        match run_state.current() {
+            RunState::Playing => run_state.overwrite_restart(),
+            RunState::GameOver => {
+                let _ = run_state.overwrite_set(RunState::Playing);
+            }
+        }
+    }
+}
+
+/// keeps the word length button showing the length being played.
+fn word_length_text_system(
+    config: Res<GameConfig>,
+    button_query: Query<&Children, With<WordLengthButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    let label = format!("{} letters", config.word_length);
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, &label);
+    }
+}
+
+/// plays on the next number of boards, the boards are laid out again when the new game starts.
+fn board_count_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<BoardCountButton>)>,
+    mut config: ResMut<GameConfig>,
+    mut run_state: ResMut<State<RunState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Clicked {
+            continue;
+        }
+        *config = config.next_board_count();
+        match run_state.current() {
+            RunState::Playing => run_state.overwrite_restart(),
+            RunState::GameOver => {
+                let _ = run_state.overwrite_set(RunState::Playing);
+            }
+        }
+    }
+}
+
+/// keeps the board count button showing how many boards are played.
+fn board_count_text_system(
+    config: Res<GameConfig>,
+    button_query: Query<&Children, With<BoardCountButton>>,
+    mut text_query: Query<&mut Text>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+    let label = match config.boards {
+        1 => "1 board".to_string(),
+        boards => format!("{} boards", boards),
+    };
+    for children in button_query.iter() {
+        set_button_label(children, &mut text_query, &label);
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::WORD_LENGTH;
+
+// compiled into the binary so the game doesn't care about the working directory.
+// (word length, answers, words that can be guessed but are never picked as the answer)
+const WORD_FILES: [(usize, &str, &str); 4] = [
+    (
+        4,
+        include_str!("../assets/words/answers4.txt"),
+        include_str!("../assets/words/allowed4.txt"),
+    ),
+    (
+        5,
+        include_str!("../assets/words/answers5.txt"),
+        include_str!("../assets/words/allowed5.txt"),
+    ),
+    (
+        6,
+        include_str!("../assets/words/answers6.txt"),
+        include_str!("../assets/words/allowed6.txt"),
+    ),
+    (
+        7,
+        include_str!("../assets/words/answers7.txt"),
+        include_str!("../assets/words/allowed7.txt"),
+    ),
+];
+
+/// Words the secret answer can be picked from and
+/// the dictionary guesses are checked against.
+/// Words are stored upper case to match the keys on the keyboard.
+/// The lists are shared, cloning a WordList doesn't copy the words.
+#[derive(Debug, Clone)]
+pub struct WordList {
+    word_length: usize,
+    answers: Arc<Vec<String>>,
+    // words that can be guessed but are never the answer, in file order.
+    allowed: Arc<Vec<String>>,
+    // answers + allowed guesses. hashed so checking a guess is a single lookup.
+    dictionary: Arc<HashSet<String>>,
+}
+
+impl Default for WordList {
+    fn default() -> Self {
+        WordList::for_length(WORD_LENGTH).expect("expect a word list for the default word length")
+    }
+}
+
+impl WordList {
+    /// the words with that many letters, None when there is no list for that length.
+    pub fn for_length(word_length: usize) -> Option<Self> {
+        let (_, answers, allowed) = WORD_FILES
+            .iter()
+            .find(|(length, _, _)| *length == word_length)?;
+        let answers = parse_words(answers);
+        let allowed = parse_words(allowed);
+        let dictionary = answers
+            .iter()
+            .chain(&allowed)
+            .cloned()
+            .collect();
+        Some(WordList {
+            word_length,
+            answers: Arc::new(answers),
+            allowed: Arc::new(allowed),
+            dictionary: Arc::new(dictionary),
+        })
+    }
+
+    /// letters in every word of this list.
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// words that can be guessed but are never picked as the answer.
+    pub fn allowed_guesses(&self) -> &[String] {
+        &self.allowed
+    }
+
+    /// picks an answer from the list.
+    /// the same seed always picks the same answer for the same list.
+    pub fn pick_answer(&self, seed: u64) -> &str {
+        let index = splitmix64(seed) % self.answers.len() as u64;
+        &self.answers[index as usize]
+    }
+
+    /// true when the word can be submitted as a guess.
+    pub fn is_allowed(&self, word: &str) -> bool {
+        self.dictionary.contains(word)
+    }
+}
+
+/// one word per line, blank lines are skipped.
+fn parse_words(text: &str) -> Vec<String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_ascii_uppercase)
+        .collect()
+}
+
+/// seed for a new game when no seed was asked for.
+pub fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+/// mixes the seed so seeds next to each other don't pick words next to each other.
+/// https://prng.di.unimi.it/splitmix64.c
+pub fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::WORD_LENGTHS;
+
+    #[test]
+    fn same_seed_picks_the_same_answer() {
+        for length in WORD_LENGTHS {
+            let words = WordList::for_length(length).expect("expect a word list for every length");
+            for seed in [0, 1, 42, u64::MAX] {
+                assert_eq!(words.pick_answer(seed), words.pick_answer(seed));
+                assert!(words.answers().iter().any(|answer| answer == words.pick_answer(seed)));
+            }
+        }
+    }
+
+    #[test]
+    fn seeds_spread_across_the_list() {
+        let words = WordList::default();
+        let count = words.answers().len();
+        // one pick per answer in the list.
+        let picks: Vec<usize> = (0..count as u64)
+            .map(|seed| {
+                let answer = words.pick_answer(seed);
+                words.answers().iter().position(|word| word == answer).unwrap()
+            })
+            .collect();
+        // random picks would land on about 63% of the words.
+        let mut different = picks.clone();
+        different.sort_unstable();
+        different.dedup();
+        assert!(different.len() > count / 2, "only {} of {} answers picked", different.len(), count);
+        // every tenth of the list gets picked from.
+        let tenth = count / 10 + 1;
+        for part in 0..10 {
+            assert!(picks.iter().any(|&index| index / tenth == part), "nothing picked in tenth {}", part);
+        }
+        // seeds next to each other don't pick words next to each other,
+        // without mixing nearly all of them would.
+        let neighbours = picks.windows(2).filter(|pair| pair[0].abs_diff(pair[1]) <= 1).count();
+        assert!(neighbours < count / 20, "{} seeds picked a word next to the last one", neighbours);
+    }
+}